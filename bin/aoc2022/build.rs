@@ -0,0 +1,58 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Inputs and example inputs embedded with the `embedded` feature, under
+/// their path in the repository, with the file name prefixes to keep.
+/// Sub-directories are included, the other years and the profiles.
+const DIRS: [(&str, &[&str]); 2] = [
+    ("input", &["day", "answers"]),
+    ("lib/solution/tests/fixtures", &[]),
+];
+
+fn main() {
+    if std::env::var_os("CARGO_FEATURE_EMBEDDED").is_none() {
+        return;
+    }
+
+    let root = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("../..");
+    let mut files = Vec::new();
+
+    for (dir, prefixes) in DIRS {
+        let path = root.join(dir);
+        // Cargo looks for changes in the whole directory.
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let mut found = Vec::new();
+        walk(&path, &mut found);
+        for file in found {
+            let name = file.file_name().unwrap_or_default().to_string_lossy().to_string();
+            if prefixes.is_empty() || prefixes.iter().any(|prefix| name.starts_with(prefix)) {
+                let relative = file.strip_prefix(&root).unwrap().to_string_lossy().replace('\\', "/");
+                files.push((relative, file.canonicalize().unwrap()));
+            }
+        }
+    }
+    files.sort();
+
+    let mut code = String::from("pub static FILES: &[(&str, &str)] = &[\n");
+    for (path, file) in files {
+        writeln!(code, "    ({:?}, include_str!({:?})),", path, file.display().to_string()).unwrap();
+    }
+    code.push_str("];\n");
+
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("embedded.rs");
+    std::fs::write(out, code).expect("unable to write the embedded files");
+}
+
+/// Every file under `dir`. Days without an input are simply absent, so is
+/// a missing directory.
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+        if path.is_dir() {
+            walk(&path, files);
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use aoc::args::{day::Day, part::Part};
+use aoc::config::Config;
+use aoc::io::Filesystem;
+use aoc::run::{RunOptions, Runner};
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    /// Days to run, every implemented day by default.
+    #[arg(long, value_delimiter = ',')]
+    pub day: Vec<Day>,
+    /// Runs of each part.
+    #[arg(long, default_value_t = 10)]
+    pub samples: usize,
+    /// Store the timings under this name.
+    #[arg(long)]
+    pub save_baseline: Option<String>,
+    /// Compare the timings with a stored baseline.
+    #[arg(long)]
+    pub compare: Option<String>,
+    #[arg(long, default_value = ".cache/baselines")]
+    pub baseline_dir: String,
+    /// Slowdowns under this many percent are never reported.
+    #[arg(long, default_value_t = 5.0)]
+    pub threshold: f64,
+    /// Time budget of a day (both parts) as DAY=MILLISECONDS, can be repeated.
+    #[arg(long, value_parser = parse_budget)]
+    pub budget: Vec<(Day, Duration)>,
+    /// Time budget of all days together in milliseconds.
+    #[arg(long)]
+    pub total_budget: Option<u64>,
+    /// Time generated inputs of growing size and estimate the complexity.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["save_baseline", "compare"])]
+    pub scale: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub name: String,
+    pub results: Vec<Measurement>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    /// Bootstrap and solution time of each run in microseconds.
+    pub samples_us: Vec<f64>,
+}
+
+impl Measurement {
+    pub fn mean(&self) -> f64 {
+        mean(&self.samples_us)
+    }
+}
+
+pub fn bench(args: &BenchArgs, config: &Config, fs: &impl Filesystem) -> Result<String, String> {
+    let days = if args.day.is_empty() { crate::registry().days(&config.year) } else { args.day.clone() };
+    if args.scale {
+        return crate::scale::scale(crate::registry().solvers(&config.year)?, &days, args.samples);
+    }
+
+    let results = measure(fs, config, &days, args.samples.max(1))?;
+
+    let baseline = match &args.compare {
+        Some(name) => Some(load(&baseline_path(&args.baseline_dir, name))?),
+        None => None,
+    };
+
+    let mut output = report(&results, baseline.as_ref(), args.threshold);
+
+    let failures = check_budgets(&results, &args.budget, args.total_budget.map(Duration::from_millis));
+
+    if let Some(name) = &args.save_baseline {
+        let path = baseline_path(&args.baseline_dir, name);
+        save(&path, &Baseline { name: name.clone(), results })?;
+        output.push_str(&format!("\nbaseline saved to {}", path.display()));
+    }
+
+    if !failures.is_empty() {
+        eprintln!("{}", output);
+        return Err(failures.join(", "));
+    }
+
+    Ok(output)
+}
+
+fn measure(fs: &impl Filesystem, config: &Config, days: &[Day], samples: usize) -> Result<Vec<Measurement>, String> {
+    let runner = Runner::new(fs)
+        .with_input_dir(&config.year_input_dir().display().to_string())
+        .with_solvers(crate::registry().solvers(&config.year)?);
+    let mut results = Vec::new();
+
+    for day in days {
+        for part in [Part::Part1, Part::Part2] {
+            let mut measurement = Measurement {
+                day: number(&format!("{}", day)),
+                part: number(&format!("{}", part)),
+                samples_us: Vec::new(),
+            };
+
+            for _ in 0..samples {
+                let report = runner.solve(day, &part, RunOptions { params: config.params(day), ..Default::default() })
+                    .map_err(|err| format!("day {} part {}: {}", day, part, err))?;
+                let elapsed = report.timings.bootstrap + report.timings.solution;
+                measurement.samples_us.push(elapsed.as_secs_f64() * 1_000_000.0);
+            }
+
+            results.push(measurement);
+        }
+    }
+
+    Ok(results)
+}
+
+fn number(value: &str) -> u8 {
+    value.parse().unwrap()
+}
+
+fn baseline_path(dir: &str, name: &str) -> PathBuf {
+    Path::new(dir).join(format!("{}.json", name))
+}
+
+fn save(path: &Path, baseline: &Baseline) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("unable to create {}: {}", dir.display(), err))?;
+    }
+
+    let content = serde_json::to_string_pretty(baseline).unwrap();
+    std::fs::write(path, content).map_err(|err| format!("unable to write {}: {}", path.display(), err))
+}
+
+fn load(path: &Path) -> Result<Baseline, String> {
+    let content = std::fs::read_to_string(path).map_err(|_| format!("unable to read baseline: {}", path.display()))?;
+
+    serde_json::from_str(&content).map_err(|err| format!("invalid baseline {}: {}", path.display(), err))
+}
+
+fn report(results: &[Measurement], baseline: Option<&Baseline>, threshold: f64) -> String {
+    let mut lines = vec![format!("{:<4} {:<5} {:>12} {:>12} {:>12} {:>9}", "day", "part", "mean", "stddev", "baseline", "change")];
+
+    for result in results {
+        let previous = baseline.and_then(|baseline| {
+            baseline.results.iter().find(|other| other.day == result.day && other.part == result.part)
+        });
+
+        let (base, change) = match previous {
+            Some(previous) => {
+                let change = (result.mean() / previous.mean() - 1.0) * 100.0;
+                let flag = if is_slower(&previous.samples_us, &result.samples_us, threshold) { "  SLOWER" } else { "" };
+                (format_us(previous.mean()), format!("{:+.1}%{}", change, flag))
+            },
+            None => ("-".to_string(), "-".to_string()),
+        };
+
+        lines.push(format!(
+            "{:<4} {:<5} {:>12} {:>12} {:>12} {:>9}",
+            format!("{:0>2}", result.day), result.part,
+            format_us(result.mean()), format_us(stddev(&result.samples_us)),
+            base, change,
+        ));
+    }
+
+    let total = results.iter().map(|result| result.mean()).sum::<f64>();
+    lines.push(format!("total {:>15}", format_us(total)));
+
+    lines.join("\n")
+}
+
+fn format_us(value: f64) -> String {
+    format!("{:?}", Duration::from_nanos((value * 1000.0) as u64))
+}
+
+fn check_budgets(results: &[Measurement], budgets: &[(Day, Duration)], total: Option<Duration>) -> Vec<String> {
+    let spent = |filter: &dyn Fn(&Measurement) -> bool| {
+        let micros = results.iter().filter(|result| filter(result)).map(|result| result.mean()).sum::<f64>();
+        Duration::from_nanos((micros * 1000.0) as u64)
+    };
+
+    let mut failures = budgets.iter()
+        .filter_map(|(day, budget)| {
+            let day_number = number(&format!("{}", day));
+            let spent = spent(&|result| result.day == day_number);
+            (spent > *budget).then(|| format!("day {} took {:?}, over its {:?} budget", day, spent, budget))
+        })
+        .collect::<Vec<String>>();
+
+    if let Some(budget) = total {
+        let spent = spent(&|_| true);
+        if spent > budget {
+            failures.push(format!("all days took {:?}, over the {:?} budget", spent, budget));
+        }
+    }
+
+    failures
+}
+
+fn parse_budget(value: &str) -> Result<(Day, Duration), String> {
+    let (day, millis) = value.split_once('=').ok_or(format!("expected DAY=MILLISECONDS: {}", value))?;
+    let millis = millis.parse::<u64>().map_err(|_| format!("invalid budget: {}", millis))?;
+
+    Ok((Day::from_str(day)?, Duration::from_millis(millis)))
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn variance(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = mean(values);
+    values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+}
+
+fn stddev(values: &[f64]) -> f64 {
+    variance(values).sqrt()
+}
+
+/// `current` is slower than `baseline` by more than `threshold` percent, and
+/// the difference is significant with a one-sided Welch's t-test at 5%.
+fn is_slower(baseline: &[f64], current: &[f64], threshold: f64) -> bool {
+    if mean(current) <= mean(baseline) * (1.0 + threshold / 100.0) {
+        return false;
+    }
+
+    let (a, b) = (variance(baseline) / baseline.len() as f64, variance(current) / current.len() as f64);
+    if a + b == 0.0 {
+        return true;
+    }
+
+    let t = (mean(current) - mean(baseline)) / (a + b).sqrt();
+    let dof = (a + b).powi(2) / (
+        a.powi(2) / (baseline.len() as f64 - 1.0).max(1.0) + b.powi(2) / (current.len() as f64 - 1.0).max(1.0)
+    );
+
+    t > t_critical(dof)
+}
+
+/// One-sided 95% critical value of Student's t distribution, Cornish-Fisher
+/// expansion around the normal quantile.
+fn t_critical(dof: f64) -> f64 {
+    let z: f64 = 1.644854;
+
+    z + (z.powi(3) + z) / (4.0 * dof)
+        + (5.0 * z.powi(5) + 16.0 * z.powi(3) + 3.0 * z) / (96.0 * dof.powi(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc::args::day::Day;
+    use aoc::config::Config;
+    use aoc::io::DirFilesystem;
+
+    use super::{Baseline, Measurement};
+
+    /// Serves the example inputs of the solutions as `input/dayNN`.
+    fn fixtures() -> DirFilesystem {
+        DirFilesystem::new("../../lib/solution/tests/fixtures").at("input")
+    }
+
+    fn measurement(day: u8, samples_us: Vec<f64>) -> Measurement {
+        Measurement { day, part: 1, samples_us }
+    }
+
+    #[test]
+    fn measure() {
+        let results = super::measure(&fixtures(), &Config::default(), &[Day::Day01, Day::Day07], 3).unwrap();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!((results[0].day, results[0].part), (1, 1));
+        assert_eq!((results[3].day, results[3].part), (7, 2));
+        assert!(results.iter().all(|result| result.samples_us.len() == 3));
+    }
+
+    #[test]
+    fn measure_missing_day() {
+        let err = super::measure(&fixtures(), &Config::default(), &[Day::Day25], 1).unwrap_err();
+
+        assert_eq!(err, "day 25 part 1: unable to read file: input/day25");
+    }
+
+    #[test]
+    fn is_slower() {
+        let baseline = [100.0, 102.0, 98.0, 101.0, 99.0];
+
+        assert!(super::is_slower(&baseline, &[150.0, 152.0, 148.0, 151.0, 149.0], 5.0));
+        // Under the threshold.
+        assert!(!super::is_slower(&baseline, &[103.0, 105.0, 101.0, 104.0, 102.0], 5.0));
+        // Faster.
+        assert!(!super::is_slower(&baseline, &[50.0, 52.0, 48.0, 51.0, 49.0], 5.0));
+        // Too noisy to tell.
+        assert!(!super::is_slower(&baseline, &[10.0, 400.0, 5.0, 200.0, 20.0], 5.0));
+    }
+
+    #[test]
+    fn t_critical() {
+        // Table values: 2.132 (4), 1.812 (10), 1.697 (30).
+        assert!((super::t_critical(4.0) - 2.132).abs() < 0.02);
+        assert!((super::t_critical(10.0) - 1.812).abs() < 0.01);
+        assert!((super::t_critical(30.0) - 1.697).abs() < 0.01);
+    }
+
+    #[test]
+    fn budgets() {
+        let results = vec![
+            measurement(1, vec![400_000.0]),
+            measurement(1, vec![400_000.0]),
+            measurement(2, vec![300_000.0]),
+        ];
+
+        let failures = super::check_budgets(&results, &[(Day::Day01, Duration::from_secs(1))], Some(Duration::from_secs(1)));
+        assert_eq!(failures, vec!["all days took 1.1s, over the 1s budget"]);
+
+        let failures = super::check_budgets(&results, &[(Day::Day01, Duration::from_millis(500)), (Day::Day02, Duration::from_secs(1))], None);
+        assert_eq!(failures, vec!["day 01 took 800ms, over its 500ms budget"]);
+    }
+
+    #[test]
+    fn parse_budget() {
+        assert_eq!(super::parse_budget("16=200"), Ok((Day::Day16, Duration::from_millis(200))));
+        assert_eq!(super::parse_budget("16"), Err("expected DAY=MILLISECONDS: 16".to_string()));
+        assert_eq!(super::parse_budget("26=1"), Err("unable to parse day: 26".to_string()));
+        assert_eq!(super::parse_budget("1=x"), Err("invalid budget: x".to_string()));
+    }
+
+    #[test]
+    fn baseline() {
+        let path = std::env::temp_dir().join(format!("aoc2022-bench-{}", std::process::id())).join("main.json");
+        let baseline = Baseline { name: "main".into(), results: vec![measurement(1, vec![1.0, 2.0])] };
+
+        super::save(&path, &baseline).unwrap();
+        assert_eq!(super::load(&path), Ok(baseline));
+        assert!(super::load(&path.with_file_name("nope.json")).unwrap_err().starts_with("unable to read baseline: "));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn report() {
+        let results = vec![measurement(1, vec![150.0, 152.0, 148.0])];
+        let baseline = Baseline { name: "main".into(), results: vec![measurement(1, vec![100.0, 102.0, 98.0])] };
+
+        let output = super::report(&results, Some(&baseline), 5.0);
+        let lines = output.lines().collect::<Vec<&str>>();
+        assert!(lines[1].starts_with("01   1"), "{}", output);
+        assert!(lines[1].ends_with("+50.0%  SLOWER"), "{}", output);
+        assert!(lines[2].starts_with("total"), "{}", output);
+    }
+}
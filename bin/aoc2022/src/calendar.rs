@@ -0,0 +1,104 @@
+use clap::Args;
+
+use aoc::args::day::Day;
+use aoc::config::Config;
+use aoc::io::Filesystem;
+
+use crate::profiles;
+
+#[derive(Args, Debug)]
+pub struct InfoArgs {
+    #[arg(long)]
+    day: Day,
+}
+
+/// Implemented days with their title and a star per part with a recorded
+/// answer in the `answers` file of the input directory.
+pub fn list(config: &Config, fs: &impl Filesystem) -> Result<String, String> {
+    let path = format!("{}/answers", profiles::input_dir(config, profiles::DEFAULT));
+    let answers = match fs.read_file(&path) {
+        Ok(content) => profiles::parse_answers(content.as_str()).map_err(|err| format!("{}: {}", path, err))?,
+        Err(_) => Default::default(),
+    };
+
+    let mut lines = Vec::new();
+    let mut total = 0;
+    let registry = crate::registry();
+    for day in registry.days(&config.year) {
+        let stars = ["1", "2"].into_iter()
+            .filter(|part| answers.contains_key(&format!("day{:0>2}-part{}", day, part)))
+            .count();
+        total += stars;
+
+        let metadata = registry.solver(&config.year, &day).metadata();
+        lines.push(format!("{:>2} {:<2} {}", day.to_string().trim_start_matches('0'), "*".repeat(stars), metadata.title));
+    }
+    lines.push(format!("{} days, {} stars", lines.len(), total));
+
+    Ok(lines.join("\n"))
+}
+
+/// Everything known about the solver of a day.
+pub fn info(args: &InfoArgs, config: &Config) -> Result<String, String> {
+    let solver = crate::registry().solver(&config.year, &args.day);
+    if !solver.is_implemented() {
+        return Err(format!("day {} of {} is not implemented", args.day, config.year));
+    }
+
+    let metadata = solver.metadata();
+    let mut lines = vec![
+        format!("Day {}: {}", args.day.to_string().trim_start_matches('0'), metadata.title),
+        metadata.url.to_string(),
+        String::new(),
+        metadata.description.to_string(),
+        String::new(),
+        format!("solver:   {}", solver.name()),
+        format!("tags:     {}", metadata.tags.join(", ")),
+        format!("examples: {}", metadata.examples),
+    ];
+
+    if !metadata.params.is_empty() {
+        lines.push("params:".to_string());
+        for param in metadata.params {
+            lines.push(format!("  {:<12} {} (default: {})", param.name, param.description, param.default));
+        }
+    }
+
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::{day::Day, year::Year};
+    use aoc::config::Config;
+    use aoc::io::InMemoryFilesystem;
+
+    use super::InfoArgs;
+
+    #[test]
+    fn list() {
+        let fs = InMemoryFilesystem::new()
+            .with_file("input/answers", "day01-part1 = 1\nday01-part2 = 2\nday03-part1 = 3\n");
+        let output = super::list(&Config::default(), &fs).unwrap();
+        let lines = output.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines[0], " 1 ** Calorie Counting");
+        assert_eq!(lines[1], " 2    Rock Paper Scissors");
+        assert_eq!(lines[2], " 3 *  Rucksack Reorganization");
+        assert_eq!(lines.last(), Some(&"16 days, 3 stars"));
+    }
+
+    #[test]
+    fn info() {
+        let output = super::info(&InfoArgs { day: Day::Day15 }, &Config::default()).unwrap();
+
+        assert!(output.starts_with("Day 15: Beacon Exclusion Zone\nhttps://adventofcode.com/2022/day/15\n"), "{}", output);
+        assert!(output.contains("tags:     geometry, ranges"), "{}", output);
+        assert!(output.contains("  target_line  Row checked by part 1, 10 in the example (default: 2000000)"), "{}", output);
+
+        assert_eq!(super::info(&InfoArgs { day: Day::Day25 }, &Config::default()), Err("day 25 of 2022 is not implemented".to_string()));
+
+        let config = Config { year: Year::new(2023), ..Default::default() };
+        assert_eq!(super::info(&InfoArgs { day: Day::Day01 }, &config), Err("day 01 of 2023 is not implemented".to_string()));
+    }
+}
@@ -0,0 +1,43 @@
+//! Requests to the configured Advent of Code endpoint, with the session token.
+use aoc::config::Config;
+
+/// Body of `path` on the endpoint, `/2022/day/1/input` for example. The
+/// User-Agent says who to reach about the requests, as the site asks of
+/// automated tools, so there has to be a contact in the configuration.
+pub fn get(config: &Config, path: &str) -> Result<String, String> {
+    let contact = config.contact.as_ref()
+        .ok_or("no contact for the User-Agent, set contact in aoc.toml or AOC_CONTACT")?;
+    let token = config.session.token().ok_or_else(|| format!("no session token in {}", config.session))?;
+    let url = format!("{}{}", config.endpoint.trim_end_matches('/'), path);
+
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", token))
+        .set("User-Agent", &user_agent(contact))
+        .call();
+
+    match response {
+        Ok(response) => response.into_string().map_err(|err| format!("unable to read {}: {}", url, err)),
+        Err(ureq::Error::Status(status, _)) => Err(format!("unable to fetch {}: status {}", url, status)),
+        Err(err) => Err(format!("unable to fetch {}: {}", url, err)),
+    }
+}
+
+fn user_agent(contact: &str) -> String {
+    format!("aoc2022/{} ({})", env!("CARGO_PKG_VERSION"), contact)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::config::Config;
+
+    #[test]
+    fn user_agent() {
+        assert_eq!(super::user_agent("elf@example.com"), format!("aoc2022/{} (elf@example.com)", env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn no_contact() {
+        let err = super::get(&Config::default(), "/2022/day/1/input").unwrap_err();
+        assert_eq!(err, "no contact for the User-Agent, set contact in aoc.toml or AOC_CONTACT");
+    }
+}
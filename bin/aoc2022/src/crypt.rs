@@ -0,0 +1,241 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+
+use aoc::args::day::Day;
+use aoc::config::Config;
+use aoc::encrypted::{self, Key, EXTENSION};
+use aoc::io::LocalFilesystem;
+
+use crate::profiles;
+
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    #[command(subcommand)]
+    command: InputCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum InputCommand {
+    /// Write dayNN.enc next to every plain input, profiles included, creates the key file if there is no key.
+    Encrypt(CryptArgs),
+    /// Write the plain input of every dayNN.enc.
+    Decrypt(CryptArgs),
+}
+
+#[derive(Args, Debug)]
+struct CryptArgs {
+    /// Only these days, comma separated. All of them by default.
+    #[arg(long, value_delimiter = ',')]
+    day: Vec<Day>,
+    /// Replace the files that already exist.
+    #[arg(long, default_value_t = false)]
+    force: bool,
+}
+
+pub fn input(args: &InputArgs, config: &Config) -> Result<String, String> {
+    match &args.command {
+        InputCommand::Encrypt(args) => {
+            let key = match Key::load(&config.key_file)? {
+                Some(key) => key,
+                None => create_key(&config.key_file)?,
+            };
+            encrypt(&key, &config.year_input_dir(), &args.day, args.force)
+        },
+        InputCommand::Decrypt(args) => {
+            let key = Key::load(&config.key_file)?.ok_or_else(|| format!(
+                "no key, set {} or create {}", encrypted::KEY_VARIABLE, config.key_file.display()
+            ))?;
+            decrypt(&key, &config.year_input_dir(), &args.day, args.force)
+        },
+    }
+}
+
+/// Write a new key, readable by the owner only from the start. An existing
+/// file is never replaced.
+fn create_key(path: &Path) -> Result<Key, String> {
+    let key = Key::generate();
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options.open(path)
+        .and_then(|mut file| writeln!(file, "{}", key.to_hex()))
+        .map_err(|err| format!("unable to write {}: {}", path.display(), err))?;
+
+    eprintln!("created {}, keep it out of the repository", path.display());
+
+    Ok(key)
+}
+
+/// Inputs of the days in the directory and in its profiles: `dayNN` and
+/// `dayNN-partP`, with the extension if `encrypted`.
+fn inputs(dir: &Path, days: &[Day], encrypted: bool) -> Result<Vec<PathBuf>, String> {
+    let mut paths = Vec::new();
+    for profile in profiles::list(&LocalFilesystem{}, dir) {
+        let dir = match profile.as_str() {
+            profiles::DEFAULT => dir.to_path_buf(),
+            profile => dir.join(profile),
+        };
+        paths.extend(profile_inputs(&dir, days, encrypted)?);
+    }
+
+    Ok(paths)
+}
+
+fn profile_inputs(dir: &Path, days: &[Day], encrypted: bool) -> Result<Vec<PathBuf>, String> {
+    let entries = std::fs::read_dir(dir).map_err(|err| format!("unable to read {}: {}", dir.display(), err))?;
+    let mut paths = entries.filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+            let name = match (encrypted, name.strip_suffix(&format!(".{}", EXTENSION))) {
+                (true, Some(name)) => name,
+                (false, None) => name,
+                _ => return false,
+            };
+
+            input_day(name).is_some_and(|day| days.is_empty() || days.contains(&day))
+        })
+        .collect::<Vec<PathBuf>>();
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Day of an input file name, `day01` or `day01-part2`.
+fn input_day(name: &str) -> Option<Day> {
+    let name = name.strip_prefix("day")?;
+    let (day, part) = match name.split_once("-part") {
+        Some((day, part)) => (day, Some(part)),
+        None => (name, None),
+    };
+
+    if day.len() != 2 || !matches!(part, None | Some("1") | Some("2")) {
+        return None;
+    }
+
+    day.parse().ok()
+}
+
+fn file_name(path: &Path) -> &str {
+    path.file_name().and_then(|name| name.to_str()).unwrap_or("")
+}
+
+fn encrypt(key: &Key, dir: &Path, days: &[Day], force: bool) -> Result<String, String> {
+    let mut lines = Vec::new();
+
+    for path in inputs(dir, days, false)? {
+        let target = path.with_file_name(format!("{}.{}", file_name(&path), EXTENSION));
+        if target.exists() && !force {
+            lines.push(format!("{} exists, skipped", target.display()));
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+        std::fs::write(&target, encrypted::encrypt(key, file_name(&path), &content))
+            .map_err(|err| format!("unable to write {}: {}", target.display(), err))?;
+        lines.push(format!("{} -> {}", path.display(), target.display()));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+fn decrypt(key: &Key, dir: &Path, days: &[Day], force: bool) -> Result<String, String> {
+    let mut lines = Vec::new();
+
+    for path in inputs(dir, days, true)? {
+        let target = path.with_extension("");
+        if target.exists() && !force {
+            lines.push(format!("{} exists, skipped", target.display()));
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+        let plain = encrypted::decrypt(key, file_name(&target), &content)?;
+        std::fs::write(&target, plain)
+            .map_err(|err| format!("unable to write {}: {}", target.display(), err))?;
+        lines.push(format!("{} -> {}", path.display(), target.display()));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::day::Day;
+    use aoc::encrypted::{EncryptedFilesystem, Key};
+    use aoc::io::{Filesystem, LocalFilesystem};
+
+    #[test]
+    fn input_day() {
+        assert_eq!(super::input_day("day01"), Some(Day::Day01));
+        assert_eq!(super::input_day("day16-part2"), Some(Day::Day16));
+        assert_eq!(super::input_day("day1"), None);
+        assert_eq!(super::input_day("day01-part3"), None);
+        assert_eq!(super::input_day("answers"), None);
+    }
+
+    #[test]
+    fn encrypt_decrypt() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-crypt-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day01"), "1\n2\n").unwrap();
+        std::fs::write(dir.join("day02-part1"), "A X\n").unwrap();
+        std::fs::write(dir.join("answers"), "day01-part1 = 3\n").unwrap();
+        std::fs::create_dir_all(dir.join("alice")).unwrap();
+        std::fs::write(dir.join("alice/day01"), "4\n").unwrap();
+        std::fs::create_dir_all(dir.join("2023")).unwrap();
+        std::fs::write(dir.join("2023/day01"), "5\n").unwrap();
+        let key = Key::generate();
+
+        let output = super::encrypt(&key, &dir, &[], false).unwrap();
+        assert_eq!(output.lines().count(), 3, "{}", output);
+        assert!(dir.join("day01.enc").exists());
+        assert!(dir.join("alice/day01.enc").exists());
+        assert!(!dir.join("2023/day01.enc").exists());
+        assert!(!dir.join("answers.enc").exists());
+
+        let output = super::encrypt(&key, &dir, &[Day::Day01], false).unwrap();
+        assert!(output.ends_with("day01.enc exists, skipped"), "{}", output);
+
+        std::fs::remove_file(dir.join("day01")).unwrap();
+        let fs = EncryptedFilesystem::new(LocalFilesystem{}, Some(key.clone()));
+        let path = dir.join("day01").display().to_string();
+        assert_eq!(fs.read_file(&path).unwrap().as_str(), "1\n2\n");
+
+        let output = super::decrypt(&key, &dir, &[], false).unwrap();
+        assert!(output.contains("day01.enc ->"), "{}", output);
+        assert!(output.contains("day02-part1 exists, skipped"), "{}", output);
+        assert_eq!(std::fs::read_to_string(dir.join("day01")).unwrap(), "1\n2\n");
+
+        let err = super::decrypt(&Key::generate(), &dir, &[], true).unwrap_err();
+        assert_eq!(err, "unable to decrypt day01, wrong key or damaged file");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn create_key() {
+        let path = std::env::temp_dir().join(format!("aoc2022-key-{}", std::process::id()));
+
+        let key = super::create_key(&path).unwrap();
+        assert_eq!(Key::load(&path), Ok(Some(key)));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        let err = super::create_key(&path).unwrap_err();
+        assert!(err.starts_with(&format!("unable to write {}: ", path.display())), "{}", err);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
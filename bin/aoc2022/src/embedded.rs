@@ -0,0 +1,91 @@
+use std::path::{Path, PathBuf};
+
+use aoc::input::Input;
+use aoc::io::{Error, Filesystem};
+
+mod files {
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+}
+
+/// Inputs and example inputs built into the binary, under their path in the
+/// repository: `input/day01`, `input/alice/day01`,
+/// `lib/solution/tests/fixtures/day01`...
+pub struct EmbeddedFilesystem {
+    files: &'static [(&'static str, &'static str)],
+    root: Option<PathBuf>,
+}
+
+impl EmbeddedFilesystem {
+    pub fn new() -> Self {
+        EmbeddedFilesystem { files: files::FILES, root: None }
+    }
+
+    /// Paths under `root` are looked up relative to it, like the absolute
+    /// paths of a configuration from an aoc.toml in `root`.
+    pub fn with_root(mut self, root: &Path) -> Self {
+        self.root = Some(root.to_path_buf());
+        self
+    }
+
+    /// Path in the repository.
+    fn relative<'a>(&self, path: &'a str) -> &'a Path {
+        let path = Path::new(path);
+        let path = self.root.as_ref()
+            .and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path);
+
+        path.strip_prefix(".").unwrap_or(path)
+    }
+
+    fn get(&self, path: &str) -> Option<&'static str> {
+        let path = self.relative(path);
+
+        self.files.iter().find(|(name, _)| Path::new(name) == path).map(|(_, content)| *content)
+    }
+}
+
+impl Filesystem for EmbeddedFilesystem {
+    fn path_exists(&self, path: &str) -> bool {
+        self.get(path).is_some()
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        self.get(path).map(Input::from).ok_or_else(|| Error::ReadError(path.to_string()))
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        aoc::io::sub_dirs(self.files.iter().map(|(name, _)| *name), self.relative(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc::io::{Error, Filesystem, LocalFilesystem};
+
+    use super::EmbeddedFilesystem;
+
+    #[test]
+    fn files() {
+        let fs = EmbeddedFilesystem::new();
+        let fixture = "lib/solution/tests/fixtures/day01";
+
+        assert!(fs.path_exists(fixture));
+        assert_eq!(fs.read_file(fixture), LocalFilesystem{}.read_file(&format!("../../{}", fixture)));
+        assert!(fs.files.iter().all(|(path, _)| path.starts_with("input/") || path.starts_with("lib/")));
+        assert_eq!(fs.read_file("input/day99"), Err(Error::ReadError("input/day99".to_string())));
+    }
+
+    #[test]
+    fn root() {
+        let fixture = "lib/solution/tests/fixtures/day01";
+        let fs = EmbeddedFilesystem::new().with_root(Path::new("/home/elf/aoc"));
+
+        assert!(fs.path_exists(&format!("/home/elf/aoc/{}", fixture)));
+        assert!(fs.path_exists(&format!("./{}", fixture)));
+        assert!(fs.path_exists(fixture));
+        assert!(!fs.path_exists(&format!("/elsewhere/{}", fixture)));
+        assert!(fs.sub_dirs("/home/elf/aoc/lib/solution/tests").contains(&"fixtures".to_string()));
+    }
+}
@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+
+use clap::Args;
+use serde::Deserialize;
+
+use aoc::config::Config;
+use aoc::io::Filesystem;
+
+use crate::client;
+
+#[derive(Args, Debug)]
+pub struct LeaderboardArgs {
+    /// Leaderboard exported as JSON from its page.
+    #[arg(long, required_unless_present = "id", conflicts_with = "id")]
+    file: Option<String>,
+    /// Private leaderboard to download from the endpoint, for the configured year.
+    #[arg(long)]
+    id: Option<u64>,
+}
+
+/// JSON of a private leaderboard, only what's shown.
+#[derive(Debug, Deserialize)]
+struct Board {
+    event: String,
+    members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Member {
+    name: Option<String>,
+    local_score: u64,
+    #[serde(default)]
+    last_star_ts: u64,
+    /// Stars by day then part.
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Star {
+    get_star_ts: u64,
+    #[serde(default)]
+    star_index: u64,
+}
+
+impl Member {
+    fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        self.completion_day_level.get(&day.to_string())?.get(&part.to_string())
+    }
+
+    fn stars(&self, day: u8) -> usize {
+        (1..=2).filter(|part| self.star(day, *part).is_some()).count()
+    }
+
+    /// Time between the two stars of a day.
+    fn delta(&self, day: u8) -> Option<u64> {
+        Some(self.star(day, 2)?.get_star_ts.saturating_sub(self.star(day, 1)?.get_star_ts))
+    }
+}
+
+/// Table of the members by local score, their stars and the time they took
+/// from part 1 to part 2.
+pub fn leaderboard(args: &LeaderboardArgs, config: &Config, fs: &impl Filesystem) -> Result<String, String> {
+    let content = match (&args.file, &args.id) {
+        (Some(path), _) => fs.read_file(path).map_err(|err| format!("{}", err))?.as_str().to_string(),
+        (None, Some(id)) => client::get(config, &format!("/{}/leaderboard/private/view/{}.json", config.year, id))?,
+        (None, None) => return Err("set --file or --id".to_string()),
+    };
+    let board: Board = serde_json::from_str(&content).map_err(|err| format!("not a leaderboard: {}", err))?;
+
+    Ok(render(&board))
+}
+
+/// Local scores from the star timestamps: on each star, the first member
+/// gets as many points as there are members, the second one less and so on.
+fn local_scores(board: &Board) -> BTreeMap<&str, u64> {
+    let mut scores = board.members.keys().map(|id| (id.as_str(), 0)).collect::<BTreeMap<&str, u64>>();
+
+    for day in (1..=25).filter(|day| counts(&board.event, *day)) {
+        for part in 1..=2 {
+            let mut stars = board.members.iter()
+                .filter_map(|(id, member)| member.star(day, part).map(|star| (star.get_star_ts, star.star_index, id.as_str())))
+                .collect::<Vec<(u64, u64, &str)>>();
+            stars.sort();
+
+            for (rank, (_, _, id)) in stars.into_iter().enumerate() {
+                *scores.entry(id).or_default() += (board.members.len() - rank) as u64;
+            }
+        }
+    }
+
+    scores
+}
+
+/// The first day of 2020 gave no points, the servers were down at unlock.
+fn counts(event: &str, day: u8) -> bool {
+    !(event == "2020" && day == 1)
+}
+
+fn render(board: &Board) -> String {
+    let scores = local_scores(board);
+    let mut members = board.members.iter()
+        .map(|(id, member)| (id.as_str(), member, scores[id.as_str()]))
+        .collect::<Vec<(&str, &Member, u64)>>();
+    members.sort_by_key(|(id, member, score)| (std::cmp::Reverse(*score), member.last_star_ts, *id));
+
+    let last_day = (1..=25u8).rev()
+        .find(|day| members.iter().any(|(_, member, _)| member.stars(*day) > 0))
+        .unwrap_or(1);
+    let days = 1..=last_day;
+    let names = members.iter()
+        .map(|(id, member, _)| member.name.clone().unwrap_or_else(|| format!("(anonymous user #{})", id)))
+        .collect::<Vec<String>>();
+
+    let mut lines = Vec::new();
+    if last_day >= 10 {
+        lines.push(format!("{:10}{}", "", days.clone().map(|day| if day < 10 { ' ' } else { char::from(b'0' + day / 10) }).collect::<String>()));
+    }
+    lines.push(format!("{:10}{}", "", days.clone().map(|day| char::from(b'0' + day % 10)).collect::<String>()));
+    let mut differences = 0;
+    for (rank, ((_, member, score), name)) in members.iter().zip(&names).enumerate() {
+        let stars = days.clone()
+            .map(|day| match member.stars(day) {
+                2 => '*',
+                1 => '+',
+                _ => '.',
+            })
+            .collect::<String>();
+        let mut line = format!("{:>3}) {:>4} {} {}", rank + 1, score, stars, name);
+        if *score != member.local_score {
+            line.push_str(&format!(" (board: {})", member.local_score));
+            differences += 1;
+        }
+        lines.push(line);
+    }
+    lines.push(match differences {
+        0 => "local scores match the board".to_string(),
+        count => format!("{} of {} local scores differ from the board", count, members.len()),
+    });
+
+    let width = names.iter().map(String::len).max().unwrap_or(0);
+    lines.push(String::new());
+    lines.push("part 1 -> part 2".to_string());
+    for ((_, member, _), name) in members.iter().zip(&names) {
+        let deltas = days.clone()
+            .filter_map(|day| member.delta(day).map(|delta| format!("{:>2}: {}", day, duration(delta))))
+            .collect::<Vec<String>>();
+        if !deltas.is_empty() {
+            lines.push(format!("{:width$}  {}", name, deltas.join("  "), width = width));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// `45s`, `3m07s` or `2h05m`.
+fn duration(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs / 60 % 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use aoc::config::{Config, SessionSource};
+    use aoc::io::InMemoryFilesystem;
+
+    use super::{Board, LeaderboardArgs};
+
+    /// Day 1 at 05:00 UTC is 1669870800.
+    const BOARD: &str = r#"{
+        "event": "2022",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 4, "local_score": 15, "global_score": 0, "last_star_ts": 1669957500,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1669870900, "star_index": 10}, "2": {"get_star_ts": 1669871000, "star_index": 20}},
+                    "2": {"1": {"get_star_ts": 1669957300, "star_index": 30}, "2": {"get_star_ts": 1669957500, "star_index": 40}}
+                }
+            },
+            "2": {
+                "id": 2, "name": "Bob", "stars": 3, "local_score": 10, "global_score": 0, "last_star_ts": 1669880000,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1669870850, "star_index": 5}, "2": {"get_star_ts": 1669880000, "star_index": 50}},
+                    "2": {"1": {"get_star_ts": 1669957400, "star_index": 35}}
+                }
+            },
+            "3": {
+                "id": 3, "name": null, "stars": 1, "local_score": 2, "global_score": 0, "last_star_ts": 1670100000,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1670100000, "star_index": 60}}
+                }
+            },
+            "4": {
+                "id": 4, "name": "Carol", "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    fn board() -> Board {
+        serde_json::from_str(BOARD).unwrap()
+    }
+
+    #[test]
+    fn local_scores() {
+        let mut board = board();
+
+        // Day 1: Bob 4 + Alice 4, Alice 3 + Bob 3, anonymous 2. Day 2: Alice 4 + Alice 4, Bob 3.
+        let scores = super::local_scores(&board).into_iter().collect::<Vec<(&str, u64)>>();
+        assert_eq!(scores, vec![("1", 15), ("2", 10), ("3", 2), ("4", 0)]);
+
+        board.event = "2020".to_string();
+        assert_eq!(super::local_scores(&board)["1"], 8);
+    }
+
+    #[test]
+    fn render() {
+        let mut board = board();
+        let output = super::render(&board);
+        let lines = output.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines, vec![
+            "          12",
+            "  1)   15 ** Alice",
+            "  2)   10 *+ Bob",
+            "  3)    2 +. (anonymous user #3)",
+            "  4)    0 .. Carol",
+            "local scores match the board",
+            "",
+            "part 1 -> part 2",
+            "Alice                 1: 1m40s   2: 3m20s",
+            "Bob                   1: 2h32m",
+        ]);
+
+        board.members.get_mut("2").unwrap().local_score = 9;
+        let output = super::render(&board);
+        assert!(output.contains("\n  2)   10 *+ Bob (board: 9)\n"), "{}", output);
+        assert!(output.contains("\n1 of 4 local scores differ from the board\n"), "{}", output);
+    }
+
+    #[test]
+    fn late_days() {
+        let board: Board = serde_json::from_str(r#"{"event": "2022", "members": {"7": {"name": "Dan", "local_score": 2,
+            "completion_day_level": {"12": {"1": {"get_star_ts": 1670821200}, "2": {"get_star_ts": 1670821245}}}}}}"#).unwrap();
+        let lines = super::render(&board).lines().map(String::from).collect::<Vec<String>>();
+
+        assert_eq!(lines[0], "                   111");
+        assert_eq!(lines[1], "          123456789012");
+        assert_eq!(lines[2], "  1)    2 ...........* Dan");
+        assert_eq!(lines[6], "Dan  12: 45s");
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(super::duration(45), "45s");
+        assert_eq!(super::duration(187), "3m07s");
+        assert_eq!(super::duration(7500), "2h05m");
+    }
+
+    #[test]
+    fn leaderboard() {
+        let fs = InMemoryFilesystem::new()
+            .with_file("board.json", BOARD)
+            .with_file("broken.json", "{\"members\": []}");
+        let args = |file: &str| LeaderboardArgs { file: Some(file.to_string()), id: None };
+
+        let output = super::leaderboard(&args("board.json"), &Config::default(), &fs).unwrap();
+        assert!(output.contains("  1)   15 ** Alice\n"), "{}", output);
+
+        let err = super::leaderboard(&args("broken.json"), &Config::default(), &fs).unwrap_err();
+        assert!(err.starts_with("not a leaderboard: "), "{}", err);
+        assert_eq!(super::leaderboard(&args("missing.json"), &Config::default(), &fs), Err("unable to read file: missing.json".to_string()));
+    }
+
+    #[test]
+    fn leaderboard_download() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let url = request.url().to_string();
+            request.respond(Response::from_string(BOARD)).unwrap();
+
+            url
+        });
+
+        let session = std::env::temp_dir().join(format!("aoc2022-leaderboard-{}", std::process::id()));
+        std::fs::write(&session, "cookie\n").unwrap();
+        let config = Config {
+            endpoint,
+            contact: Some("elf@example.com".to_string()),
+            session: SessionSource::File(session.clone()),
+            ..Default::default()
+        };
+        let args = LeaderboardArgs { file: None, id: Some(123) };
+
+        let output = super::leaderboard(&args, &config, &InMemoryFilesystem::new()).unwrap();
+        assert!(output.contains("  1)   15 ** Alice\n"), "{}", output);
+        assert_eq!(handle.join().unwrap(), "/2022/leaderboard/private/view/123.json");
+
+        std::fs::remove_file(session).unwrap();
+    }
+}
@@ -1,240 +1,522 @@
-use std::time::Instant;
+mod bench;
+mod calendar;
+mod client;
+mod crypt;
+#[cfg(feature = "embedded")]
+mod embedded;
+mod leaderboard;
+mod next;
+mod profiles;
+mod scale;
+mod serve;
+mod solve;
+mod watch;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 
 use aoc::Solver;
-use aoc::args::{Args, part::Part, day::Day};
-use clap::Parser;
+use aoc::args::{Args, day::Day, year::Year};
+use clap::{Parser, Subcommand};
 use aoc::io::{Filesystem, LocalFilesystem};
+use aoc::external::ExternalSolver;
+use aoc::plugin::{self, Plugin};
+use aoc::progress::Progress;
+use aoc::cache::{self, Cache, CacheMode};
+use aoc::config::{Config, OutputFormat, Overrides};
+use aoc::encrypted::EncryptedFilesystem;
+use aoc::registry::Registry;
+use aoc::run::{RunOptions, Runner};
+use script::ScriptSolver;
+
+const CACHE_DIR: &str = ".cache/answers";
+
+/// Plugins of each directory, loaded once per process.
+static PLUGINS: Mutex<BTreeMap<String, Arc<Vec<Plugin>>>> = Mutex::new(BTreeMap::new());
+
+#[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    args: Option<Args>,
+    #[command(flatten)]
+    config: Overrides,
+    /// Read the inputs built into the binary instead of the local files.
+    #[arg(long, global = true, default_value_t = false)]
+    embedded: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve puzzles over HTTP.
+    Serve(serve::ServeArgs),
+    /// Time every day, compare with stored baselines and check budgets.
+    Bench(bench::BenchArgs),
+    /// Print the configuration in effect, with the environment and the flags applied.
+    Config,
+    /// Encrypt or decrypt the puzzle inputs.
+    Input(crypt::InputArgs),
+    /// Solve both parts of an input, the day is detected when it's not given.
+    Solve(solve::SolveArgs),
+    /// Show the implemented days and their stars.
+    List,
+    /// Show the title, tags and parameters of a day.
+    Info(calendar::InfoArgs),
+    /// Solve a day again every time its input changes.
+    Watch(watch::WatchArgs),
+    /// Count down to the next puzzle, then scaffold its day and fetch its input.
+    Next(next::NextArgs),
+    /// Show a private leaderboard with its stars and recomputed local scores.
+    Leaderboard(leaderboard::LeaderboardArgs),
+}
 
 fn main() {
-    let args = Args::parse();
-    let fs = LocalFilesystem{};
+    let cli = Cli::parse();
+
+    let result = Config::load(&cli.config).and_then(|config| {
+        // dayNN.enc inputs are decrypted on the fly, the key is only loaded
+        // for the first one.
+        let root = config.file.as_deref().and_then(Path::parent);
+        let fs = EncryptedFilesystem::with_key_file(filesystem(cli.embedded, root)?, &config.key_file);
+
+        match (&cli.command, &cli.args) {
+            (Some(Command::Serve(args)), _) => serve::serve(args, &config).map(|_| String::new()),
+            (Some(Command::Bench(args)), _) => bench::bench(args, &config, &fs),
+            (Some(Command::Config), _) => Ok(format!("{}", config)),
+            (Some(Command::Input(args)), _) => crypt::input(args, &config),
+            (Some(Command::Solve(args)), _) => solve::solve(args, &config, &fs),
+            (Some(Command::List), _) => calendar::list(&config, &fs),
+            (Some(Command::Info(args)), _) => calendar::info(args, &config),
+            (Some(Command::Watch(args)), _) => watch::watch(args, &config, &fs),
+            (Some(Command::Next(args)), _) => next::next(args, &config, &next::SystemClock),
+            (Some(Command::Leaderboard(args)), _) => leaderboard::leaderboard(args, &config, &fs),
+            (None, Some(args)) if args.all_profiles => {
+                profiles::check(args, &config, &fs, &profiles::list(&fs, &config.year_input_dir()))
+            },
+            (None, Some(args)) => {
+                let progress = Progress::new();
+                interrupt_with(progress.clone());
+                run(args, &config, &fs, Some(progress))
+            },
+            (None, None) => Err("nothing to do, see --help".to_string()),
+        }
+    });
 
-    match run(&args, &fs) {
+    match result {
+        Ok(value) if value.is_empty() => {},
         Ok(value) => println!("{}", value),
-        Err(err) => eprintln!("error: {}", err),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        },
     }
 }
 
-fn run(args: &Args, fs: &impl Filesystem) -> Result<String, String> {
-    let filename = match &args.input {
-        Some(name) => name.clone(),
-        None => input_filename(fs, &args.day, &args.part)
-    };
+/// Solution crates of every year.
+fn registry() -> Registry {
+    Registry::new()
+        .with_year(Year::new(2022), solution::get_solver)
+}
 
-    let input = match fs.read_file(&filename) {
-        Ok(inp) => inp,
-        Err(err) => { return Err(format!("{}", err)) },
-    };
+#[cfg(feature = "embedded")]
+fn filesystem(embedded: bool, root: Option<&Path>) -> Result<Box<dyn Filesystem>, String> {
+    let files = embedded::EmbeddedFilesystem::new();
+    match (embedded, root) {
+        (true, Some(root)) => Ok(Box::new(files.with_root(root))),
+        (true, None) => Ok(Box::new(files)),
+        (false, _) => Ok(Box::new(LocalFilesystem{})),
+    }
+}
 
-    let mut solver = get_solver(&args.day);
+#[cfg(not(feature = "embedded"))]
+fn filesystem(embedded: bool, _root: Option<&Path>) -> Result<Box<dyn Filesystem>, String> {
+    match embedded {
+        true => Err("no embedded inputs, build with --features embedded".to_string()),
+        false => Ok(Box::new(LocalFilesystem{})),
+    }
+}
 
-    let mut start_time = Instant::now();
-    solver.read_lines(input);
-    if args.time_it {
-        eprintln!(" -- Bootstrap solver: {:?}", start_time.elapsed());
+/// Print how far the solver got when it's interrupted with Ctrl-C.
+fn interrupt_with(progress: Progress) {
+    let result = ctrlc::set_handler(move || {
+        if progress.is_started() {
+            eprintln!("\ninterrupted: {}", progress.snapshot());
+        } else {
+            eprintln!("\ninterrupted");
+        }
+
+        std::process::exit(130);
+    });
+
+    if let Err(err) = result {
+        eprintln!("error: unable to handle Ctrl-C: {}", err);
     }
+}
 
-    start_time = Instant::now();
-    let answer = match args.part {
-        Part::Part1 => solver.part1(),
-        Part::Part2 => solver.part2(),
+fn run(args: &Args, config: &Config, fs: &impl Filesystem, progress: Option<Progress>) -> Result<String, String> {
+    let options = RunOptions {
+        input: args.input.clone(),
+        solver: build_solver(args, config, fs)?,
+        timeout: args.timeout.or(config.timeout).map(Duration::from_secs),
+        progress,
+        cache: cache_mode(args),
+        variant: solver_variant(args, fs),
+        params: config.params(&args.day),
     };
+
+    let report = Runner::new(fs)
+        .with_input_dir(&profiles::input_dir(config, args.profile.as_deref().unwrap_or(profiles::DEFAULT)))
+        .with_solvers(registry().solvers(&config.year)?)
+        .with_cache(Cache::new(Path::new(CACHE_DIR).join(config.year.to_string()), &cache::build_id()))
+        .solve(&args.day, &args.part, options)
+        .map_err(|err| format!("{}", err))?;
+
+    if report.cached {
+        eprintln!(" -- Answer from cache");
+    }
+
+    if config.format == OutputFormat::Json {
+        return Ok(serde_json::json!({
+            "year": config.year.number(),
+            "day": format!("{}", report.day).parse::<u8>().unwrap(),
+            "part": format!("{}", report.part).parse::<u8>().unwrap(),
+            "solver": report.solver,
+            "input": report.input,
+            "answer": report.answer,
+            "cached": report.cached,
+            "bootstrap_us": report.timings.bootstrap.as_micros() as u64,
+            "solution_us": report.timings.solution.as_micros() as u64,
+        }).to_string());
+    }
+
     if args.time_it {
-        eprintln!(" -- Solution: {:?}", start_time.elapsed());
+        eprintln!(" -- Bootstrap solver: {:?}", report.timings.bootstrap);
+        eprintln!(" -- Solution: {:?}", report.timings.solution);
     }
 
-    Ok(answer)
+    Ok(report.answer)
 }
 
-fn input_filename(fs: &impl Filesystem, day: &Day, part: &Part) -> String {
-    let file_path_without_part = format!("input/day{:0>2}", day);
-    let file_path_with_part = format!("input/day{:0>2}-part{}", day, part);
-
-    if fs.path_exists(&file_path_with_part) {
-        file_path_with_part
+fn cache_mode(args: &Args) -> CacheMode {
+    if args.no_cache {
+        CacheMode::Disabled
+    } else if args.cached {
+        CacheMode::Use
     } else {
-        file_path_without_part
+        CacheMode::Store
     }
 }
 
-fn get_solver(day: &Day) -> Box<dyn Solver> {
-    match day {
-        Day::Day01 => Box::new(solution::day01::Solution::new()),
-        Day::Day02 => Box::new(solution::day02::Solution::new()),
-        Day::Day03 => Box::new(solution::day03::Solution::new()),
-        Day::Day04 => Box::new(solution::day04::Solution::new()),
-        Day::Day05 => Box::new(solution::day05::Solution::new()),
-        Day::Day06 => Box::new(solution::day06::Solution::new()),
-        Day::Day07 => Box::new(solution::day07::Solution::new()),
-        Day::Day08 => Box::new(solution::day08::Solution::new()),
-        Day::Day09 => Box::new(solution::day09::Solution::new()),
-        Day::Day10 => Box::new(solution::day10::Solution::new()),
-        Day::Day11 => Box::new(solution::day11::Solution::new()),
-        Day::Day12 => Box::new(solution::day12::Solution::new()),
-        Day::Day13 => Box::new(solution::day13::Solution::new()),
-        Day::Day14 => Box::new(solution::day14::Solution::new()),
-        Day::Day15 => Box::new(solution::day15::Solution::new()),
-        Day::Day16 => Box::new(solution::day16::Solution::new()),
-        Day::Day17 => Box::new(aoc::MissingSolution::new()),
-        Day::Day18 => Box::new(aoc::MissingSolution::new()),
-        Day::Day19 => Box::new(aoc::MissingSolution::new()),
-        Day::Day20 => Box::new(aoc::MissingSolution::new()),
-        Day::Day21 => Box::new(aoc::MissingSolution::new()),
-        Day::Day22 => Box::new(aoc::MissingSolution::new()),
-        Day::Day23 => Box::new(aoc::MissingSolution::new()),
-        Day::Day24 => Box::new(aoc::MissingSolution::new()),
-        Day::Day25 => Box::new(aoc::MissingSolution::new()),
+/// What makes the answers of a scripted solver different from the other
+/// runs of the same kind. External and plugin solvers tell it themselves.
+fn solver_variant(args: &Args, fs: &impl Filesystem) -> Option<String> {
+    if args.script {
+        let source = fs.read_file(&script_filename(&args.day)).ok()?;
+        return Some(format!("{:016x}", cache::hash(source.as_bytes())));
     }
+
+    None
 }
 
-#[cfg(test)]
-mod tests {
-    use std::collections::HashMap;
-    use aoc::args::{part::Part, day::Day, Args};
-    use aoc::io::Error;
-    use super::Filesystem;
-
-    struct InMemoryFilesystem {
-        valid_paths: HashMap<String, Vec<String>>,
-    }
-
-    impl InMemoryFilesystem {
-        fn new() -> Self {
-            let mut map = HashMap::new();
-            map.insert(
-                "input/day01".into(),
-                vec![
-                    "100", "200",
-                    "",
-                    "20", "400",
-                    "",
-                    "100",
-                    "",
-                    "150", "30", "20",
-                    "",
-                ].iter().map(|c| c.to_string() ).collect(),
-            );
-            map.insert("input/day02-part1".into(), Vec::new());
-            map.insert("input/day02-part2".into(), Vec::new());
-
-            InMemoryFilesystem{
-                valid_paths: map,
-            }
-        }
+fn script_filename(day: &Day) -> String {
+    format!("scripts/day{:0>2}.rhai", day)
+}
+
+/// Solver picked by the command line flags, `None` to use the built-in one.
+fn build_solver(args: &Args, config: &Config, fs: &impl Filesystem) -> Result<Option<Box<dyn Solver>>, String> {
+    if let Some(command) = &args.external {
+        let solver = ExternalSolver::new(command, args.day.clone())
+            .with_timeout(Duration::from_secs(args.external_timeout));
+
+        return Ok(Some(Box::new(solver)));
     }
 
-    impl Filesystem for InMemoryFilesystem {
-        fn path_exists(&self, path: &str) -> bool {
-            self.valid_paths.contains_key(path)
-        }
+    if args.script {
+        let filename = script_filename(&args.day);
+        let source = fs.read_file(&filename).map_err(|err| format!("{}", err))?;
 
-        fn read_file(&self, path: &str) -> Result<Vec<String>, Error> {
-            match self.valid_paths.get(path) {
-                None => Err(Error::ReadError(path.to_string())),
-                Some(content) => Ok(content.clone()),
-            }
-        }
+        return Ok(Some(Box::new(ScriptSolver::new(&filename, source.as_str())?)));
+    }
+
+    // Plugins don't shadow the built-in solvers unless they are asked for.
+    if args.plugin.is_none() && registry().solver(&config.year, &args.day).is_implemented() {
+        return Ok(None);
+    }
+
+    let solver = plugins(&args.plugin_dir).iter()
+        .filter(|plugin| args.plugin.as_ref().is_none_or(|name| plugin.name() == name))
+        .find_map(|plugin| plugin.solver(&args.day));
+
+    match (solver, &args.plugin) {
+        (Some(solver), _) => Ok(Some(Box::new(solver))),
+        (None, Some(name)) => Err(format!("no plugin {} with day {} in {}", name, args.day, args.plugin_dir)),
+        (None, None) => Ok(None),
     }
+}
 
-    impl InMemoryFilesystem {
-        fn remove(&mut self, path: &str) {
-            self.valid_paths.remove(path);
+/// Plugins in `dir`, the ones that can't be loaded are reported the first
+/// time only.
+fn plugins(dir: &str) -> Arc<Vec<Plugin>> {
+    let mut loaded = PLUGINS.lock().unwrap_or_else(PoisonError::into_inner);
+    let plugins = loaded.entry(dir.to_string()).or_insert_with(|| {
+        let (plugins, errors) = plugin::discover(Path::new(dir));
+        for err in errors {
+            eprintln!("error: {}", err);
         }
 
-        fn add(&mut self, path: String, content: Vec<String>) {
-            self.valid_paths.insert(path, content);
+        Arc::new(plugins)
+    });
+
+    Arc::clone(plugins)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::{part::Part, day::Day, Args};
+    use aoc::config::{Config, OutputFormat};
+    use aoc::input::Input;
+    use aoc::io::InMemoryFilesystem;
+
+    /// Arguments of a plain run, without the cache or plugins.
+    fn args(day: Day, part: Part) -> Args {
+        Args {
+            day,
+            part,
+            time_it: false,
+            input: None,
+            external: None,
+            external_timeout: 30,
+            script: false,
+            plugin_dir: "__dir_is_not_here__".into(),
+            plugin: None,
+            timeout: None,
+            cached: false,
+            no_cache: true,
+            profile: None,
+            all_profiles: false,
         }
     }
 
-    #[test]
-    fn input_filename() {
-        let fs = InMemoryFilesystem::new();
-
-        // No part1 and part2 files
-        assert_eq!(super::input_filename(&fs, &Day::Day01, &Part::Part1), "input/day01".to_string());
-        assert_eq!(super::input_filename(&fs, &Day::Day01, &Part::Part2), "input/day01".to_string());
-        // Has separate part1 and part2 files
-        assert_eq!(super::input_filename(&fs, &Day::Day02, &Part::Part1), "input/day02-part1".to_string());
-        assert_eq!(super::input_filename(&fs, &Day::Day02, &Part::Part2), "input/day02-part2".to_string());
+    fn filesystem() -> InMemoryFilesystem {
+        InMemoryFilesystem::new()
+            .with_file("input/day01", "100\n200\n\n20\n400\n\n100\n\n150\n30\n20\n\n")
+            .with_file("input/day02-part1", "")
+            .with_file("input/day02-part2", "")
     }
 
     #[test]
     fn run() {
-        let args = Args{
-            day: Day::Day01,
-            part: Part::Part1,
-            time_it: false,
-            input: None,
-        };
-        let fs = InMemoryFilesystem::new();
+        let args = args(Day::Day01, Part::Part1);
+        let fs = filesystem();
 
-        let answer = super::run(&args, &fs);
-        assert!(!answer.is_err());
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert!(answer.is_ok());
         assert_eq!(answer.unwrap(), "420".to_string());
     }
 
     #[test]
-    fn run_no_input() {
-        let args = Args{
-            day: Day::Day01,
-            part: Part::Part1,
-            time_it: false,
-            input: None,
+    fn run_with_config() {
+        let args = args(Day::Day01, Part::Part2);
+        let fs = filesystem();
+
+        let config = Config { format: OutputFormat::Json, ..Default::default() };
+        let output = super::run(&args, &config, &fs, None).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(report["day"], 1);
+        assert_eq!(report["part"], 2);
+        assert_eq!(report["answer"], "920");
+        assert_eq!(report["input"], "input/day01");
+
+        let config = Config { input_dir: "elsewhere".into(), ..Default::default() };
+        let answer = super::run(&args, &config, &fs, None);
+        assert_eq!(answer, Err("unable to read file: elsewhere/day01".to_string()));
+
+        let config = Config::parse("[days.1]\nspeed = 2").unwrap();
+        let answer = super::run(&args, &config, &fs, None);
+        assert_eq!(answer, Err("day 01: unknown parameter: speed".to_string()));
+    }
+
+    #[test]
+    fn run_profiles() {
+        let mut args = Args {
+            profile: Some("alice".into()),
+            ..args(Day::Day01, Part::Part1)
         };
-        let mut fs = InMemoryFilesystem::new();
+        let mut fs = filesystem();
+        // No blank line after the last group.
+        fs.insert("input/alice/day01", "1\n2\n\n4\n");
+        fs.insert("input/alice/answers", "day01-part1 = 4\n");
+        fs.insert("input/bob/day01", "5\n");
+        fs.insert("input/bob/answers", "day01-part1 = 6\n");
+        fs.insert("input/carol/day02", "A X\n");
+
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Ok("4".to_string()));
+
+        args.profile = None;
+        args.all_profiles = true;
+        let profiles = ["default", "alice", "bob", "carol"].map(String::from);
+        let output = super::profiles::check(&args, &Config::default(), &fs, &profiles).unwrap_err();
+        let lines = output.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines.len(), 4, "{}", output);
+        assert!(lines[0].starts_with("default") && lines[0].ends_with("420              no recorded answer"), "{}", output);
+        assert!(lines[1].starts_with("alice") && lines[1].ends_with(" ok"), "{}", output);
+        assert!(lines[2].starts_with("bob") && lines[2].ends_with("WRONG, expected 6"), "{}", output);
+        assert_eq!(lines[3], "1 of 3 profiles failed");
+
+        let output = super::profiles::check(&args, &Config::default(), &fs, &profiles[1..2]);
+        assert!(output.is_ok(), "{:?}", output);
+
+        args.day = Day::Day03;
+        let output = super::profiles::check(&args, &Config::default(), &fs, &profiles);
+        assert_eq!(output, Err("no profile has an input for day 03".to_string()));
+    }
+
+    #[test]
+    fn run_no_input() {
+        let args = args(Day::Day01, Part::Part1);
+        let mut fs = filesystem();
         fs.remove("input/day01");
 
-        let answer = super::run(&args, &fs);
+        let answer = super::run(&args, &Config::default(), &fs, None);
         assert!(answer.is_err());
     }
 
     #[test]
     fn run_custom_input() {
-        let args = Args{
-            day: Day::Day02,
-            part: Part::Part1,
-            time_it: false,
+        let args = Args {
             input: Some("input/random".into()),
+            ..args(Day::Day02, Part::Part1)
         };
-        let mut fs = InMemoryFilesystem::new();
-        fs.add("input/random".into(), Vec::new());
+        let mut fs = filesystem();
+        fs.insert("input/random", "");
 
-        let answer = super::run(&args, &fs);
+        let answer = super::run(&args, &Config::default(), &fs, None);
         assert!(answer.is_ok());
         assert_eq!(answer.unwrap(), "0".to_string());
     }
 
     #[test]
     fn run_custom_input_no_input() {
-        let args = Args{
-            day: Day::Day01,
-            part: Part::Part1,
-            time_it: false,
+        let args = Args {
             input: Some("input/random".into()),
+            ..args(Day::Day01, Part::Part1)
         };
-        let fs = InMemoryFilesystem::new();
+        let fs = filesystem();
 
-        let answer = super::run(&args, &fs);
+        let answer = super::run(&args, &Config::default(), &fs, None);
         assert!(answer.is_err());
     }
 
-    // To make sure we load the right solver.
     #[test]
-    fn get_solver() {
-        let days = vec![
-            (Day::Day01, true), (Day::Day02, true), (Day::Day03, true), (Day::Day04, true), (Day::Day05, true),
-            (Day::Day06, true), (Day::Day07, true), (Day::Day08, true), (Day::Day09, true), (Day::Day10, true),
-            (Day::Day11, true), (Day::Day12, true), (Day::Day13, true), (Day::Day14, true), (Day::Day15, true),
-            (Day::Day16, true), (Day::Day17, false), (Day::Day18, false), (Day::Day19, false), (Day::Day20, false),
-            (Day::Day21, false), (Day::Day22, false), (Day::Day23, false), (Day::Day24, false), (Day::Day25, false),
-        ];
-
-        for (day, implemented) in days {
-            if implemented {
-                assert_eq!(super::get_solver(&day).name(), &format!("day{}::Solution", day))
-            } else {
-                assert_eq!(super::get_solver(&day).name(), "MissingSolution")
-            }
-        }
+    fn run_external() {
+        let args = Args {
+            external: Some("sh ../../lib/aoc/tests/fixtures/external_solver.sh".into()),
+            ..args(Day::Day01, Part::Part2)
+        };
+        let fs = filesystem();
+
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Ok("1020".to_string()));
+    }
+
+    #[test]
+    fn run_external_error() {
+        let args = Args {
+            input: Some("input/day01".into()),
+            external: Some("sh ../../lib/aoc/tests/fixtures/external_solver.sh".into()),
+            ..args(Day::Day03, Part::Part1)
+        };
+        let fs = filesystem();
+
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Err("external command failed with exit code 3: something went wrong".to_string()));
+    }
+
+    #[test]
+    fn run_script() {
+        let args = Args {
+            script: true,
+            ..args(Day::Day01, Part::Part2)
+        };
+        let mut fs = filesystem();
+        fs.insert("scripts/day01.rhai", [
+            "fn part1(lines) { lines.len() }",
+            "fn part2(lines) { lines.filter(|l| l != \"\").len() }",
+        ].iter().copied().collect::<Input>());
+
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Ok("8".to_string()));
+    }
+
+    #[test]
+    fn run_script_error() {
+        let args = Args {
+            script: true,
+            ..args(Day::Day01, Part::Part1)
+        };
+        let mut fs = filesystem();
+
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Err("unable to read file: scripts/day01.rhai".to_string()));
+
+        fs.insert("scripts/day01.rhai", [
+            "fn part1(lines) {",
+            "    lines[100]",
+            "}",
+        ].iter().copied().collect::<Input>());
+
+        let answer = super::run(&args, &Config::default(), &fs, None).unwrap_err();
+        assert!(answer.starts_with("scripts/day01.rhai: part1: "), "{}", answer);
+        assert!(answer.contains("line 2"), "{}", answer);
+    }
+
+    #[test]
+    fn run_plugin() {
+        // Test binaries live next to the libraries of their dependencies.
+        let library = format!("{}example_plugin{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX);
+        let source = std::env::current_exe().unwrap().parent().unwrap().join(&library);
+        let plugin_dir = std::env::temp_dir().join(format!("aoc2022-plugins-{}", std::process::id()));
+        std::fs::create_dir_all(&plugin_dir).unwrap();
+        std::fs::copy(source, plugin_dir.join(&library)).unwrap();
+
+        let mut args = Args {
+            input: Some("input/day01".into()),
+            plugin_dir: plugin_dir.display().to_string(),
+            ..args(Day::Day17, Part::Part2)
+        };
+        let fs = filesystem();
+
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Ok("21".to_string()));
+
+        // Days the plugin doesn't know still use the built-in solvers.
+        args.day = Day::Day01;
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Ok("920".to_string()));
+
+        args.plugin = Some("example".into());
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Err(format!("no plugin example with day 01 in {}", plugin_dir.display())));
+
+        args.day = Day::Day17;
+        let answer = super::run(&args, &Config::default(), &fs, None);
+        assert_eq!(answer, Ok("21".to_string()));
+
+        std::fs::remove_dir_all(plugin_dir).unwrap();
+    }
+
+    #[cfg(not(feature = "embedded"))]
+    #[test]
+    fn filesystem_not_embedded() {
+        let err = super::filesystem(true, None).err().unwrap();
+
+        assert_eq!(err, "no embedded inputs, build with --features embedded");
+        assert!(super::filesystem(false, None).is_ok());
     }
 }
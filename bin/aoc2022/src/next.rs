@@ -0,0 +1,349 @@
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use clap::Args;
+
+use aoc::args::{day::Day, year::Year};
+use aoc::config::Config;
+use aoc::external::split_command;
+
+use crate::client;
+
+/// Puzzles unlock at midnight UTC-5.
+const UNLOCK_OFFSET: u64 = 5 * 3600;
+
+#[derive(Args, Debug)]
+pub struct NextArgs {
+    /// Only print the time left, don't wait for the unlock.
+    #[arg(long, default_value_t = false)]
+    no_wait: bool,
+    /// Scaffolding command, run with the day and the year as arguments, and
+    /// the configured directories in AOC_TEMPLATE_DIR and AOC_FIXTURE_DIR.
+    #[arg(long, default_value = "python3 scripts/gen_day.py")]
+    scaffold: String,
+    /// Command opening the module of the day, $EDITOR by default.
+    #[arg(long)]
+    editor: Option<String>,
+}
+
+/// Where the time comes from, a fake one in the tests.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+/// Next puzzle to unlock and when.
+#[derive(Debug, PartialEq)]
+struct Unlock {
+    year: Year,
+    day: u8,
+    at: SystemTime,
+}
+
+/// Wait for the next puzzle with a countdown, then scaffold its day, fetch
+/// its input and open its module.
+pub fn next(args: &NextArgs, config: &Config, clock: &impl Clock) -> Result<String, String> {
+    let unlock = next_unlock(clock.now())?;
+    let puzzle = format!("day {} of {}", unlock.day, unlock.year);
+
+    loop {
+        let left = match unlock.at.duration_since(clock.now()) {
+            Ok(left) if !left.is_zero() => left,
+            _ => break,
+        };
+        if args.no_wait {
+            return Ok(format!("{} unlocks in {}", puzzle, countdown(left)));
+        }
+
+        eprint!("\r{} unlocks in {} ", puzzle, countdown(left));
+        clock.sleep(left.min(Duration::from_secs(1)));
+    }
+    eprintln!();
+
+    let day = Day::all().remove(unlock.day as usize - 1);
+    let mut lines = vec![format!("{} is unlocked", puzzle)];
+
+    let dirs = [("AOC_TEMPLATE_DIR", config.template_dir.as_path()), ("AOC_FIXTURE_DIR", config.fixture_dir.as_path())];
+    run(&args.scaffold, &[unlock.day.to_string(), unlock.year.to_string()], &dirs)?;
+
+    let input_dir = Config { year: unlock.year, ..config.clone() }.year_input_dir();
+    let input = input_dir.join(format!("day{:0>2}", day));
+    if input.exists() {
+        lines.push(format!("{} is already there", input.display()));
+    } else {
+        let content = client::get(config, &format!("/{}/day/{}/input", unlock.year, unlock.day))?;
+        std::fs::create_dir_all(&input_dir).map_err(|err| format!("unable to create {}: {}", input_dir.display(), err))?;
+        std::fs::write(&input, content).map_err(|err| format!("unable to write {}: {}", input.display(), err))?;
+        lines.push(format!("wrote {}", input.display()));
+    }
+
+    let module = module_path(&unlock.year, &day);
+    if let Some(editor) = args.editor.clone().or_else(|| std::env::var("EDITOR").ok()) {
+        run(&editor, std::slice::from_ref(&module), &[])?;
+    }
+    lines.push(module);
+
+    Ok(lines.join("\n"))
+}
+
+/// First unlock strictly after `now`, the 1st of December after the 25th.
+/// An error for a clock set before the first event.
+fn next_unlock(now: SystemTime) -> Result<Unlock, String> {
+    let secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let (year, month, day) = civil_from_days(((secs - secs.min(UNLOCK_OFFSET)) / 86400) as i64);
+
+    let (year, day) = match (month, day) {
+        (12, 25..) => (year + 1, 1),
+        (12, day) => (year, day + 1),
+        _ => (year, 1),
+    };
+    let at = days_from_civil(year, 12, day) as u64 * 86400 + UNLOCK_OFFSET;
+
+    let year = u16::try_from(year)
+        .map_err(|_| format!("there is no Advent of Code in {}", year))
+        .and_then(Year::try_from)?;
+
+    Ok(Unlock {
+        year,
+        day: day as u8,
+        at: UNIX_EPOCH + Duration::from_secs(at),
+    })
+}
+
+/// Days since 1970-01-01 of a date, Howard Hinnant's `days_from_civil`.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// Year, month and day of a number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+fn countdown(left: Duration) -> String {
+    let secs = left.as_secs();
+    let clock = format!("{:02}:{:02}:{:02}", secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    match secs / 86400 {
+        0 => clock,
+        days => format!("{}d {}", days, clock),
+    }
+}
+
+/// Module of a day, where scripts/gen_day.py writes it.
+fn module_path(year: &Year, day: &Day) -> String {
+    let crate_dir = match *year == Year::new(2022) {
+        true => "lib/solution".to_string(),
+        false => format!("lib/solution{}", year),
+    };
+
+    format!("{}/src/day{:0>2}/mod.rs", crate_dir, day)
+}
+
+/// Run a command line, split like the external solvers, with more arguments,
+/// more environment variables and the terminal of this one.
+fn run(command: &str, args: &[String], env: &[(&str, &Path)]) -> Result<(), String> {
+    let words = split_command(command).map_err(|err| format!("{}", err))?;
+    let (program, leading) = words.split_first().ok_or(format!("empty command: {:?}", command))?;
+
+    let status = Command::new(program)
+        .args(leading)
+        .args(args)
+        .envs(env.iter().copied())
+        .status()
+        .map_err(|err| format!("unable to run {}: {}", command, err))?;
+
+    match status.success() {
+        true => Ok(()),
+        false => Err(format!("{} {} failed with {}", command, args.join(" "), status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::thread;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use tiny_http::{Response, Server};
+
+    use aoc::args::{day::Day, year::Year};
+    use aoc::config::{Config, SessionSource};
+
+    use super::{Clock, NextArgs, Unlock};
+
+    /// 2022-12-01T00:00:00Z
+    const DECEMBER_2022: u64 = 1669852800;
+
+    /// Clock that only moves when slept on.
+    struct FakeClock {
+        now: Cell<SystemTime>,
+        slept: Cell<Duration>,
+    }
+
+    impl FakeClock {
+        fn at(secs: u64) -> Self {
+            Self { now: Cell::new(UNIX_EPOCH + Duration::from_secs(secs)), slept: Cell::new(Duration::ZERO) }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+            self.slept.set(self.slept.get() + duration);
+        }
+    }
+
+    fn unlock(year: u16, day: u8, secs: u64) -> Unlock {
+        Unlock { year: Year::new(year), day, at: UNIX_EPOCH + Duration::from_secs(secs) }
+    }
+
+    #[test]
+    fn next_unlock() {
+        let at = |secs: u64| super::next_unlock(UNIX_EPOCH + Duration::from_secs(secs)).unwrap();
+        let hour = 3600;
+
+        assert_eq!(at(DECEMBER_2022 - 20 * 86400), unlock(2022, 1, DECEMBER_2022 + 5 * hour));
+        assert_eq!(at(DECEMBER_2022 + 86400 + 5 * hour - 1), unlock(2022, 2, DECEMBER_2022 + 86400 + 5 * hour));
+        assert_eq!(at(DECEMBER_2022 + 86400 + 5 * hour), unlock(2022, 3, DECEMBER_2022 + 2 * 86400 + 5 * hour));
+        // 2023-12-01T05:00:00Z
+        assert_eq!(at(DECEMBER_2022 + 24 * 86400 + 5 * hour), unlock(2023, 1, 1701406800));
+
+        assert_eq!(super::next_unlock(UNIX_EPOCH), Err("there is no Advent of Code in 1970".to_string()));
+    }
+
+    #[test]
+    fn civil() {
+        assert_eq!(super::civil_from_days(0), (1970, 1, 1));
+        assert_eq!(super::civil_from_days(19327), (2022, 12, 1));
+        assert_eq!(super::civil_from_days(19782), (2024, 2, 29));
+        assert_eq!(super::days_from_civil(2022, 12, 1), 19327);
+        assert_eq!(super::days_from_civil(2024, 2, 29), 19782);
+    }
+
+    #[test]
+    fn countdown() {
+        assert_eq!(super::countdown(Duration::from_secs(2)), "00:00:02");
+        assert_eq!(super::countdown(Duration::from_secs(3 * 86400 + 4 * 3600 + 5 * 60 + 6)), "3d 04:05:06");
+    }
+
+    #[test]
+    fn module_path() {
+        assert_eq!(super::module_path(&Year::new(2022), &Day::Day02), "lib/solution/src/day02/mod.rs");
+        assert_eq!(super::module_path(&Year::new(2023), &Day::Day12), "lib/solution2023/src/day12/mod.rs");
+    }
+
+    #[test]
+    fn next() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-next-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(".session"), "cookie\n").unwrap();
+
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let header = |name: &'static str| request.headers().iter()
+                .find(|header| header.field.equiv(name))
+                .map(|header| header.value.to_string());
+            let (cookie, agent) = (header("Cookie"), header("User-Agent"));
+            let url = request.url().to_string();
+            request.respond(Response::from_string("1\n2\n")).unwrap();
+
+            (url, cookie, agent)
+        });
+
+        let config = Config {
+            input_dir: dir.join("input"),
+            session: SessionSource::File(dir.join(".session")),
+            endpoint,
+            contact: Some("elf@example.com".to_string()),
+            fixture_dir: "fixtures".into(),
+            ..Default::default()
+        };
+        let mut args = NextArgs {
+            no_wait: true,
+            scaffold: r#"sh -c 'test "$0 $1 $AOC_FIXTURE_DIR" = "2 2022 fixtures"'"#.to_string(),
+            editor: Some(r#"sh -c 'test "$0" = lib/solution/src/day02/mod.rs'"#.to_string()),
+        };
+        let clock = FakeClock::at(DECEMBER_2022 + 86400 + 5 * 3600 - 2);
+
+        assert_eq!(super::next(&args, &config, &clock), Ok("day 2 of 2022 unlocks in 00:00:02".to_string()));
+        assert_eq!(clock.slept.get(), Duration::ZERO);
+
+        args.no_wait = false;
+        let output = super::next(&args, &config, &clock).unwrap();
+        let input = dir.join("input/day02");
+        assert_eq!(output, format!("day 2 of 2022 is unlocked\nwrote {}\nlib/solution/src/day02/mod.rs", input.display()));
+        assert_eq!(clock.slept.get(), Duration::from_secs(2));
+        assert_eq!(std::fs::read_to_string(&input).unwrap(), "1\n2\n");
+        let (url, cookie, agent) = handle.join().unwrap();
+        assert_eq!(url, "/2022/day/2/input");
+        assert_eq!(cookie, Some("session=cookie".to_string()));
+        assert!(agent.is_some_and(|agent| agent.ends_with(" (elf@example.com)")));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn next_errors() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            request.respond(Response::from_string("Please log in").with_status_code(400)).unwrap();
+        });
+
+        let dir = std::env::temp_dir().join(format!("aoc2022-next-errors-{}", std::process::id()));
+        let config = Config { input_dir: dir.clone(), session: SessionSource::File(dir.join(".session")), endpoint, contact: Some("elf@example.com".to_string()), ..Default::default() };
+        let mut args = NextArgs { no_wait: false, scaffold: "false".to_string(), editor: Some("true".to_string()) };
+        let clock = FakeClock::at(DECEMBER_2022 + 5 * 3600 - 1);
+        assert_eq!(super::next(&args, &config, &clock), Err("false 1 2022 failed with exit status: 1".to_string()));
+
+        args.scaffold = "true".to_string();
+        let clock = FakeClock::at(DECEMBER_2022 + 5 * 3600 - 1);
+        assert_eq!(super::next(&args, &config, &clock), Err(format!("no session token in file:{}", dir.join(".session").display())));
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(".session"), "expired\n").unwrap();
+        let clock = FakeClock::at(DECEMBER_2022 + 5 * 3600 - 1);
+        let err = super::next(&args, &config, &clock).unwrap_err();
+        assert_eq!(err, format!("unable to fetch {}/2022/day/1/input: status 400", config.endpoint));
+        assert!(!dir.join("day01").exists());
+
+        handle.join().unwrap();
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use aoc::args::{day::Day, part::Part, year::Year, Args};
+use aoc::config::Config;
+use aoc::io::Filesystem;
+use aoc::run::{RunOptions, Runner};
+
+/// Profile of the inputs right in the input directory.
+pub const DEFAULT: &str = "default";
+
+/// Sub-directories of the input directory, each one holds the inputs and
+/// the answers of someone else. The input directory itself comes first, the
+/// directories of the other years are not profiles.
+pub fn list(fs: &impl Filesystem, input_dir: &Path) -> Vec<String> {
+    let mut profiles = fs.sub_dirs(&input_dir.display().to_string())
+        .into_iter()
+        .filter(|name| name.parse::<Year>().is_err())
+        .collect::<Vec<String>>();
+    profiles.insert(0, DEFAULT.to_string());
+
+    profiles
+}
+
+/// Directory of the inputs of the profile, for the year of the
+/// configuration.
+pub fn input_dir(config: &Config, profile: &str) -> String {
+    match profile {
+        DEFAULT => config.year_input_dir().display().to_string(),
+        profile => config.year_input_dir().join(profile).display().to_string(),
+    }
+}
+
+/// Answers recorded in the `answers` file of a profile, one
+/// `dayNN-partP = answer` per line.
+pub fn parse_answers(content: &str) -> Result<HashMap<String, String>, String> {
+    content.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| match line.split_once('=') {
+            Some((key, answer)) => Ok((key.trim().to_string(), answer.trim().to_string())),
+            None => Err(format!("expected dayNN-partP = answer, got: {}", line)),
+        })
+        .collect()
+}
+
+fn answer_key(day: &Day, part: &Part) -> String {
+    format!("day{:0>2}-part{}", day, part)
+}
+
+/// Solve the day with the input of every profile that has one and compare
+/// with its recorded answer. Any wrong answer or failure is an error.
+pub fn check(args: &Args, config: &Config, fs: &impl Filesystem, profiles: &[String]) -> Result<String, String> {
+    let mut lines = Vec::new();
+    let mut failures = 0;
+
+    for profile in profiles {
+        let dir = input_dir(config, profile);
+        let runner = Runner::new(fs)
+            .with_input_dir(&dir)
+            .with_solvers(crate::registry().solvers(&config.year)?);
+
+        if !fs.path_exists(&runner.input_filename(&args.day, &args.part)) {
+            continue;
+        }
+
+        let expected = match fs.read_file(&format!("{}/answers", dir)) {
+            Ok(content) => parse_answers(content.as_str())
+                .map_err(|err| format!("{}/answers: {}", dir, err))?
+                .remove(&answer_key(&args.day, &args.part)),
+            Err(_) => None,
+        };
+
+        let options = RunOptions {
+            solver: crate::build_solver(args, config, fs)?,
+            timeout: args.timeout.or(config.timeout).map(std::time::Duration::from_secs),
+            cache: crate::cache_mode(args),
+            variant: crate::solver_variant(args, fs),
+            params: config.params(&args.day),
+            ..Default::default()
+        };
+
+        let status = match (runner.solve(&args.day, &args.part, options), expected) {
+            (Err(err), _) => {
+                failures += 1;
+                format!("{:<12} error: {}", profile, err)
+            },
+            (Ok(report), Some(expected)) if report.answer != expected => {
+                failures += 1;
+                format!("{:<12} {:<16} WRONG, expected {}", profile, report.answer, expected)
+            },
+            (Ok(report), Some(_)) => format!("{:<12} {:<16} ok", profile, report.answer),
+            (Ok(report), None) => format!("{:<12} {:<16} no recorded answer", profile, report.answer),
+        };
+        lines.push(status);
+    }
+
+    if lines.is_empty() {
+        return Err(format!("no profile has an input for day {}", args.day));
+    }
+
+    if failures > 0 {
+        lines.push(format!("{} of {} profiles failed", failures, lines.len()));
+        return Err(lines.join("\n"));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    use aoc::io::{InMemoryFilesystem, LocalFilesystem};
+
+    #[test]
+    fn parse_answers() {
+        let answers = super::parse_answers("# alice\nday01-part1 = 24000\n\nday01-part2=45000\n").unwrap();
+
+        assert_eq!(answers, HashMap::from([
+            ("day01-part1".to_string(), "24000".to_string()),
+            ("day01-part2".to_string(), "45000".to_string()),
+        ]));
+        assert_eq!(super::parse_answers("24000").unwrap_err(), "expected dayNN-partP = answer, got: 24000");
+    }
+
+    #[test]
+    fn list() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-profiles-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("bob")).unwrap();
+        std::fs::create_dir_all(dir.join("alice")).unwrap();
+        std::fs::create_dir_all(dir.join("2023")).unwrap();
+        std::fs::write(dir.join("day01"), "1").unwrap();
+
+        assert_eq!(super::list(&LocalFilesystem{}, &dir), vec!["default", "alice", "bob"]);
+        assert_eq!(super::list(&LocalFilesystem{}, &dir.join("missing")), vec!["default"]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+
+        // Profiles that only exist in the filesystem, like embedded ones.
+        let fs = InMemoryFilesystem::new()
+            .with_file("input/day01", "")
+            .with_file("input/carol/day01", "")
+            .with_file("input/2023/day01", "");
+        assert_eq!(super::list(&fs, Path::new("input")), vec!["default", "carol"]);
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use aoc::args::{day::Day, part::Part};
+use aoc::input::Input;
+use aoc::registry::Solvers;
+use aoc::run;
+use solution::generate::{self, Generator};
+
+type Growth = fn(f64) -> f64;
+
+/// Complexity classes and their growth function.
+const CLASSES: [(&str, Growth); 6] = [
+    ("O(1)", |_| 1.0),
+    ("O(log n)", |n| n.ln()),
+    ("O(n)", |n| n),
+    ("O(n log n)", |n| n * n.ln()),
+    ("O(n^2)", |n| n * n),
+    ("O(n^3)", |n| n * n * n),
+];
+
+/// Time each part of the days with an input generator on growing inputs.
+pub fn scale(solvers: Solvers, days: &[Day], samples: usize) -> Result<String, String> {
+    let mut lines = Vec::new();
+
+    for day in days {
+        let generator = match generate::get_generator(day) {
+            Some(generator) => generator,
+            None if days.len() == 1 => return Err(format!("day {} has no input generator", day)),
+            None => continue,
+        };
+
+        for part in generator.parts {
+            let timings = measure(solvers, day, part, &generator, samples)?;
+            let points = timings.iter()
+                .map(|(size, elapsed)| (*size as f64, elapsed.as_secs_f64()))
+                .collect::<Vec<(f64, f64)>>();
+
+            lines.push(format!("day {:0>2} part {}: {}", day, part, fit(&points)));
+            for (size, elapsed) in timings {
+                lines.push(format!("  {:>8} {:>14?}", size, elapsed));
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        return Err("none of the days has an input generator".to_string());
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Fastest of `samples` runs for each size.
+fn measure(solvers: Solvers, day: &Day, part: &Part, generator: &Generator, samples: usize) -> Result<Vec<(usize, Duration)>, String> {
+    generator.sizes.iter()
+        .map(|size| {
+            let input = Input::from((generator.generate)(*size));
+            let fastest = (0..samples.max(1))
+                .map(|_| {
+                    let mut solver = solvers(day);
+                    let (_, timings) = run::execute(solver.as_mut(), part, &input)
+                        .map_err(|err| format!("day {} part {} size {}: {}", day, part, size, err))?;
+
+                    Ok(timings.bootstrap + timings.solution)
+                })
+                .collect::<Result<Vec<Duration>, String>>()?
+                .into_iter()
+                .min()
+                .unwrap_or_default();
+
+            Ok((*size, fastest))
+        })
+        .collect()
+}
+
+/// Complexity class that fits the (size, time) points best. The fit is done
+/// on the logarithms so every size counts the same.
+fn fit(points: &[(f64, f64)]) -> &'static str {
+    let points = points.iter()
+        .filter(|(size, time)| *size > 1.0 && *time > 0.0)
+        .collect::<Vec<&(f64, f64)>>();
+
+    if points.len() < 2 {
+        return "O(?)";
+    }
+
+    CLASSES.iter()
+        .map(|(name, growth)| {
+            let offsets = points.iter()
+                .map(|(size, time)| time.ln() - growth(*size).ln())
+                .collect::<Vec<f64>>();
+            let mean = offsets.iter().sum::<f64>() / offsets.len() as f64;
+            let residual = offsets.iter().map(|offset| (offset - mean).powi(2)).sum::<f64>();
+
+            (name, residual)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(name, _)| *name)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::day::Day;
+
+    fn points(growth: super::Growth) -> Vec<(f64, f64)> {
+        [100.0, 200.0, 400.0, 800.0, 1600.0, 3200.0].iter()
+            .enumerate()
+            // A bit of noise, alternating above and below.
+            .map(|(idx, n)| (*n, growth(*n) * 1e-6 * if idx % 2 == 0 { 1.05 } else { 0.95 }))
+            .collect()
+    }
+
+    #[test]
+    fn fit() {
+        assert_eq!(super::fit(&points(|_| 3.0)), "O(1)");
+        assert_eq!(super::fit(&points(|n| n.ln())), "O(log n)");
+        assert_eq!(super::fit(&points(|n| 5.0 * n)), "O(n)");
+        assert_eq!(super::fit(&points(|n| n * n.ln())), "O(n log n)");
+        assert_eq!(super::fit(&points(|n| 0.1 * n * n)), "O(n^2)");
+        assert_eq!(super::fit(&points(|n| n * n * n)), "O(n^3)");
+        assert_eq!(super::fit(&[(100.0, 1.0)]), "O(?)");
+    }
+
+    #[test]
+    fn scale() {
+        let output = super::scale(solution::get_solver, &[Day::Day06], 1).unwrap();
+
+        assert!(output.starts_with("day 06 part 1: O("), "{}", output);
+        assert!(output.contains("day 06 part 2: O("), "{}", output);
+        assert!(output.contains("     32000 "), "{}", output);
+    }
+
+    #[test]
+    fn no_generator() {
+        assert_eq!(super::scale(solution::get_solver, &[Day::Day01], 1), Err("day 01 has no input generator".to_string()));
+        assert_eq!(super::scale(solution::get_solver, &[Day::Day01, Day::Day02], 1), Err("none of the days has an input generator".to_string()));
+    }
+}
@@ -0,0 +1,314 @@
+use std::io::Read;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use clap::Args;
+use serde::Serialize;
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use aoc::args::{day::Day, part::Part};
+use aoc::config::Config;
+use aoc::input::Input;
+use aoc::registry::Solvers;
+use aoc::run;
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    #[arg(long, default_value_t = 8080)]
+    pub port: u16,
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+    /// Seconds a single request can take to solve.
+    #[arg(long, default_value_t = 10)]
+    pub timeout: u64,
+    /// Largest accepted puzzle input in bytes.
+    #[arg(long, default_value_t = 1024 * 1024)]
+    pub max_body: usize,
+    /// Requests handled at the same time, the others wait in the queue.
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+    pub workers: u16,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Solved {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub timings: Timings,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Timings {
+    pub bootstrap_us: u64,
+    pub solution_us: u64,
+}
+
+/// Start the server and handle requests until the process is stopped, the
+/// puzzles are the ones of the configured year.
+///
+/// * `GET /days`: list of the implemented days.
+/// * `POST /solve/{day}/{part}`: solve the puzzle input in the body.
+pub fn serve(args: &ServeArgs, config: &Config) -> Result<(), String> {
+    let solvers = crate::registry().solvers(&config.year)?;
+    let server = Server::http(format!("{}:{}", args.host, args.port))
+        .map_err(|err| format!("unable to start server: {}", err))?;
+
+    eprintln!(" -- Listening on http://{}", server.server_addr());
+
+    listen(server, solvers, args.workers as usize, Duration::from_secs(args.timeout), args.max_body);
+
+    Ok(())
+}
+
+/// Answer the requests with a fixed number of threads, so a burst of
+/// requests can't start as many solvers.
+fn listen(server: Server, solvers: Solvers, workers: usize, timeout: Duration, max_body: usize) {
+    let server = Arc::new(server);
+    let handles = (0..workers)
+        .map(|_| {
+            let server = Arc::clone(&server);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(request, solvers, timeout, max_body);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        let _ = handle.join();
+    }
+}
+
+fn respond(mut request: Request, solvers: Solvers, timeout: Duration, max_body: usize) {
+    let method = request.method().clone();
+    let url = request.url().to_string();
+    let length = request.body_length();
+
+    let (status, body) = handle(&method, &url, request.as_reader(), length, solvers, timeout, max_body);
+
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+
+    if let Err(err) = request.respond(response) {
+        eprintln!("error: unable to respond to {} {}: {}", method, url, err);
+    }
+}
+
+fn handle(
+    method: &Method,
+    url: &str,
+    body: &mut dyn Read,
+    length: Option<usize>,
+    solvers: Solvers,
+    timeout: Duration,
+    max_body: usize,
+) -> (u16, serde_json::Value) {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments = path.trim_matches('/').split('/').collect::<Vec<&str>>();
+
+    match (method, segments.as_slice()) {
+        (Method::Get, ["days"]) => (200, json!({ "days": implemented_days(solvers) })),
+        (Method::Post, ["solve", day, part]) => {
+            let day = match Day::from_str(day) {
+                Ok(day) => day,
+                Err(err) => return error(400, err),
+            };
+            let part = match Part::from_str(part) {
+                Ok(part) => part,
+                Err(err) => return error(400, err),
+            };
+
+            let input = match read_body(body, length, max_body) {
+                Ok(input) => input,
+                Err(err) => return err,
+            };
+
+            match solve(solvers, day, part, input, timeout) {
+                Ok(solved) => (200, serde_json::to_value(solved).unwrap()),
+                Err((status, err)) => error(status, err),
+            }
+        },
+        (_, ["days"]) | (_, ["solve", _, _]) => error(405, format!("method not allowed: {}", method)),
+        _ => error(404, format!("not found: {}", path)),
+    }
+}
+
+fn error(status: u16, message: String) -> (u16, serde_json::Value) {
+    (status, json!({ "error": message }))
+}
+
+fn read_body(body: &mut dyn Read, length: Option<usize>, max_body: usize) -> Result<String, (u16, serde_json::Value)> {
+    let too_large = || error(413, format!("input is larger than {} bytes", max_body));
+
+    if length.unwrap_or_default() > max_body {
+        return Err(too_large());
+    }
+
+    // Don't trust the header (it can be missing with chunked encoding),
+    // never read more than one byte over the limit.
+    let mut buffer = Vec::new();
+    if let Err(err) = body.take(max_body as u64 + 1).read_to_end(&mut buffer) {
+        return Err(error(400, format!("unable to read input: {}", err)));
+    }
+    if buffer.len() > max_body {
+        return Err(too_large());
+    }
+
+    String::from_utf8(buffer).map_err(|_| error(400, "input is not valid UTF-8".to_string()))
+}
+
+fn implemented_days(solvers: Solvers) -> Vec<u8> {
+    Day::all().iter()
+        .filter(|day| solvers(day).is_implemented())
+        .map(|day| format!("{}", day).parse::<u8>().unwrap())
+        .collect()
+}
+
+fn solve(solvers: Solvers, day: Day, part: Part, input: String, timeout: Duration) -> Result<Solved, (u16, String)> {
+    if !solvers(&day).is_implemented() {
+        return Err((404, format!("day {} is not implemented", day)));
+    }
+
+    let day_number = format!("{}", day).parse::<u8>().unwrap();
+    let part_number = format!("{}", part).parse::<u8>().unwrap();
+
+    // A slow solver can't hold the response, it's cancelled after the timeout.
+    match run::execute_timeout(solvers(&day), &part, Input::from(input), timeout) {
+        Ok((answer, timings)) => Ok(Solved {
+            day: day_number,
+            part: part_number,
+            answer,
+            timings: Timings {
+                bootstrap_us: timings.bootstrap.as_micros() as u64,
+                solution_us: timings.solution.as_micros() as u64,
+            },
+        }),
+        Err(err @ run::Error::Timeout(_)) => Err((504, format!("{}", err))),
+        Err(err) => Err((500, format!("{}", err))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use std::time::Duration;
+
+    use serde_json::json;
+    use tiny_http::{Method, Server};
+
+    use aoc::args::year::Year;
+    use aoc::config::Config;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const MAX_BODY: usize = 1024;
+
+    fn handle(method: Method, url: &str, body: &str) -> (u16, serde_json::Value) {
+        super::handle(&method, url, &mut body.as_bytes(), Some(body.len()), solution::get_solver, TIMEOUT, MAX_BODY)
+    }
+
+    fn fixture(day: &str) -> String {
+        std::fs::read_to_string(format!("../../lib/solution/tests/fixtures/{}", day)).unwrap()
+    }
+
+    #[test]
+    fn days() {
+        let (status, body) = handle(Method::Get, "/days", "");
+
+        assert_eq!(status, 200);
+        assert_eq!(body["days"][0], 1);
+        assert_eq!(body["days"][15], 16);
+        assert_eq!(body["days"].as_array().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn solve() {
+        let (status, body) = handle(Method::Post, "/solve/1/2", &fixture("day01"));
+
+        assert_eq!(status, 200);
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["part"], 2);
+        assert_eq!(body["answer"], "45000");
+        assert!(body["timings"]["bootstrap_us"].is_u64());
+        assert!(body["timings"]["solution_us"].is_u64());
+    }
+
+    #[test]
+    fn errors() {
+        let cases = vec![
+            (Method::Get, "/nope", "", 404, "not found: /nope"),
+            (Method::Post, "/days", "", 405, "method not allowed: POST"),
+            (Method::Get, "/solve/1/1", "", 405, "method not allowed: GET"),
+            (Method::Post, "/solve/26/1", "", 400, "unable to parse day: 26"),
+            (Method::Post, "/solve/1/3", "", 400, "unable to parse part: 3"),
+            (Method::Post, "/solve/25/1", "", 404, "day 25 is not implemented"),
+            (Method::Post, "/solve/1/1", "x", 500, "solver panicked: called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }"),
+        ];
+
+        for (method, url, body, status, message) in cases {
+            assert_eq!(handle(method, url, body), (status, json!({ "error": message })), "{}", url);
+        }
+    }
+
+    #[test]
+    fn too_large() {
+        let body = "1\n".repeat(MAX_BODY);
+        let expected = (413, json!({ "error": "input is larger than 1024 bytes" }));
+
+        // Content-Length over the limit.
+        assert_eq!(handle(Method::Post, "/solve/1/1", &body), expected);
+        // No Content-Length, the body itself is over the limit.
+        assert_eq!(super::handle(&Method::Post, "/solve/1/1", &mut body.as_bytes(), None, solution::get_solver, TIMEOUT, MAX_BODY), expected);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let body: &[u8] = &[0xff, 0xfe];
+        let response = super::handle(&Method::Post, "/solve/1/1", &mut &body[..], None, solution::get_solver, TIMEOUT, MAX_BODY);
+
+        assert_eq!(response, (400, json!({ "error": "input is not valid UTF-8" })));
+    }
+
+    #[test]
+    fn timeout() {
+        let timeout = Duration::ZERO;
+        let (status, _) = super::handle(&Method::Post, "/solve/16/2", &mut fixture("day16").as_bytes(), None, solution::get_solver, timeout, 10 * MAX_BODY);
+
+        assert_eq!(status, 504);
+    }
+
+    #[test]
+    fn unknown_year() {
+        let args = super::ServeArgs { port: 0, host: "127.0.0.1".into(), timeout: 10, max_body: MAX_BODY, workers: 1 };
+        let config = Config { year: Year::new(2015), ..Config::default() };
+
+        assert_eq!(super::serve(&args, &config), Err("no solutions for 2015".to_string()));
+    }
+
+    #[test]
+    fn server() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        std::thread::spawn(move || super::listen(server, solution::get_solver, 2, TIMEOUT, MAX_BODY));
+
+        let body = fixture("day01");
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /solve/01/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(), body,
+        ).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert!(response.contains("Content-Type: application/json"), "{}", response);
+        assert!(response.contains(r#""answer":"24000""#), "{}", response);
+    }
+}
@@ -0,0 +1,115 @@
+use std::io::Read;
+use std::time::Duration;
+
+use clap::Args;
+
+use aoc::args::{day::Day, part::Part, year::Year};
+use aoc::config::{Config, OutputFormat};
+use aoc::io::{Filesystem, InMemoryFilesystem};
+use aoc::run::{RunOptions, Runner};
+
+#[derive(Args, Debug)]
+pub struct SolveArgs {
+    /// Input file, `-` for the standard input.
+    #[arg(long)]
+    input: String,
+    /// Day of the input, detected from its format when missing.
+    #[arg(long)]
+    day: Option<Day>,
+}
+
+/// Solve both parts of an input, of any day.
+pub fn solve(args: &SolveArgs, config: &Config, fs: &impl Filesystem) -> Result<String, String> {
+    let content = match args.input.as_str() {
+        "-" => {
+            let mut content = String::new();
+            std::io::stdin().read_to_string(&mut content).map_err(|err| format!("unable to read the standard input: {}", err))?;
+            content
+        },
+        path => fs.read_file(path).map_err(|err| format!("{}", err))?.as_str().to_string(),
+    };
+
+    let (day, detected) = match &args.day {
+        Some(day) => (day.clone(), false),
+        // The formats known are the ones of 2022.
+        None if config.year == Year::new(2022) => (pick(solution::detect::detect(&content))?, true),
+        None => return Err(format!("unable to detect the day of a {} input, set it with --day", config.year)),
+    };
+
+    let fs = InMemoryFilesystem::new().with_file(&args.input, content);
+    let runner = Runner::new(&fs).with_solvers(crate::registry().solvers(&config.year)?);
+    let mut answers = Vec::new();
+
+    for part in [Part::Part1, Part::Part2] {
+        let options = RunOptions {
+            input: Some(args.input.clone()),
+            timeout: config.timeout.map(Duration::from_secs),
+            params: config.params(&day),
+            ..Default::default()
+        };
+        let report = runner.solve(&day, &part, options).map_err(|err| format!("{}", err))?;
+        answers.push(report.answer);
+    }
+
+    if config.format == OutputFormat::Json {
+        return Ok(serde_json::json!({
+            "day": format!("{}", day).parse::<u8>().unwrap(),
+            "detected": detected,
+            "part1": answers[0],
+            "part2": answers[1],
+        }).to_string());
+    }
+
+    let mut lines = Vec::new();
+    if detected {
+        lines.push(format!("day {} (detected)", day));
+    }
+    lines.push(format!("part 1: {}", answers[0]));
+    lines.push(format!("part 2: {}", answers[1]));
+
+    Ok(lines.join("\n"))
+}
+
+/// The only day the input can be.
+fn pick(days: Vec<Day>) -> Result<Day, String> {
+    match days.as_slice() {
+        [day] => Ok(day.clone()),
+        [] => Err("unable to detect the day of the input, set it with --day".to_string()),
+        days => Err(format!(
+            "the input could be the one of days {}, set it with --day",
+            days.iter().map(|day| day.to_string()).collect::<Vec<String>>().join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::day::Day;
+    use aoc::config::{Config, OutputFormat};
+    use aoc::io::DirFilesystem;
+
+    use super::SolveArgs;
+
+    #[test]
+    fn pick() {
+        assert_eq!(super::pick(vec![Day::Day10]), Ok(Day::Day10));
+        assert_eq!(super::pick(vec![]), Err("unable to detect the day of the input, set it with --day".to_string()));
+        assert_eq!(super::pick(vec![Day::Day03, Day::Day06]), Err("the input could be the one of days 03, 06, set it with --day".to_string()));
+    }
+
+    #[test]
+    fn solve() {
+        let fs = DirFilesystem::new("../../lib/solution/tests/fixtures");
+        let args = SolveArgs { input: "day01".to_string(), day: None };
+
+        assert_eq!(super::solve(&args, &Config::default(), &fs), Ok("day 01 (detected)\npart 1: 24000\npart 2: 45000".to_string()));
+
+        let args = SolveArgs { input: "day10".to_string(), day: Some(Day::Day10) };
+        let config = Config { format: OutputFormat::Json, ..Default::default() };
+        let output = super::solve(&args, &config, &fs).unwrap();
+        assert!(output.starts_with(r#"{"day":10,"detected":false,"part1":"13140","#), "{}", output);
+
+        let args = SolveArgs { input: "empty".to_string(), day: None };
+        assert!(super::solve(&args, &Config::default(), &fs).unwrap_err().starts_with("unable to detect"));
+    }
+}
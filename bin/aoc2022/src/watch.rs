@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use aoc::args::{day::Day, part::Part};
+use aoc::config::Config;
+use aoc::encrypted::EXTENSION;
+use aoc::io::Filesystem;
+use aoc::run::{RunOptions, Runner};
+
+use crate::profiles;
+
+const STATE_DIR: &str = ".cache/watch";
+/// Used without a configured timeout, a half-written input shouldn't hang
+/// or crash the loop.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    #[arg(long)]
+    day: Day,
+    /// Only this part, both by default.
+    #[arg(long)]
+    part: Option<Part>,
+    /// Input to watch instead of input/dayNN, a fixture for example.
+    #[arg(long)]
+    input: Option<String>,
+    /// Wait this long for the writes to settle before running again.
+    #[arg(long, default_value_t = 200)]
+    debounce_ms: u64,
+}
+
+/// Answer or error of one part, with the time it took.
+#[derive(Debug, Clone, PartialEq)]
+struct Outcome {
+    part: Part,
+    answer: Result<String, String>,
+    solution: Duration,
+}
+
+/// Solve the day, then again every time its input changes. The previous
+/// answers are kept in `.cache/watch`, so the diff still works when
+/// `cargo watch` restarts the command after a change in the sources.
+pub fn watch(args: &WatchArgs, config: &Config, fs: &impl Filesystem) -> Result<String, String> {
+    let parts = match &args.part {
+        Some(part) => vec![part.clone()],
+        None => vec![Part::Part1, Part::Part2],
+    };
+    let input_dir = profiles::input_dir(config, profiles::DEFAULT);
+    let runner = Runner::new(fs)
+        .with_input_dir(&input_dir)
+        .with_solvers(crate::registry().solvers(&config.year)?);
+    let mut files = parts.iter()
+        .map(|part| args.input.clone().unwrap_or_else(|| runner.input_filename(&args.day, part)))
+        .collect::<Vec<String>>();
+    files.dedup();
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|err| format!("unable to watch: {}", err))?;
+    let watched = watched_paths(&candidates(&input_dir, &args.day, &parts, args.input.as_deref()))?;
+    let mut dirs = watched.iter().filter_map(|path| path.parent()).collect::<Vec<&Path>>();
+    dirs.dedup();
+    for dir in dirs {
+        // Editors often replace the file, the directory sees it.
+        watcher.watch(dir, RecursiveMode::NonRecursive).map_err(|err| format!("unable to watch {}: {}", dir.display(), err))?;
+    }
+
+    let state = Path::new(STATE_DIR).join(config.year.to_string()).join(format!("day{:0>2}", args.day));
+    let mut previous = load_state(&state);
+    eprintln!("watching {}, Ctrl-C to stop", files.join(", "));
+
+    loop {
+        let outcomes = parts.iter()
+            .map(|part| {
+                let options = RunOptions {
+                    input: args.input.clone(),
+                    // Panics are only caught with a timeout.
+                    timeout: Some(config.timeout.map_or(DEFAULT_TIMEOUT, Duration::from_secs)),
+                    params: config.params(&args.day),
+                    ..Default::default()
+                };
+                match runner.solve(&args.day, part, options) {
+                    Ok(report) => Outcome { part: part.clone(), answer: Ok(report.answer), solution: report.timings.solution },
+                    Err(err) => Outcome { part: part.clone(), answer: Err(format!("{}", err)), solution: Duration::ZERO },
+                }
+            })
+            .collect::<Vec<Outcome>>();
+
+        println!("{}\n", render(&outcomes, &previous));
+        for outcome in &outcomes {
+            if let Ok(answer) = &outcome.answer {
+                previous.insert(format!("{}", outcome.part), answer.clone());
+            }
+        }
+        save_state(&state, &previous);
+
+        let changed = wait_for_change(&rx, &watched, Duration::from_millis(args.debounce_ms), None)?;
+        let changed = changed.iter().map(|path| path.display().to_string()).collect::<Vec<String>>();
+        println!("-- {} changed", changed.join(", "));
+    }
+}
+
+/// Every file the input of the parts can come from, existing or not yet:
+/// `dayNN`, `dayNN-partP` and their encrypted `.enc`, or only the given
+/// input and its `.enc`.
+fn candidates(input_dir: &str, day: &Day, parts: &[Part], input: Option<&str>) -> Vec<String> {
+    let plain = match input {
+        Some(input) => vec![input.to_string()],
+        None => std::iter::once(format!("{}/day{:0>2}", input_dir, day))
+            .chain(parts.iter().map(|part| format!("{}/day{:0>2}-part{}", input_dir, day, part)))
+            .collect(),
+    };
+
+    plain.iter()
+        .flat_map(|file| [file.clone(), format!("{}.{}", file, EXTENSION)])
+        .collect()
+}
+
+/// Absolute paths of the files, they don't have to exist yet but their
+/// directory does.
+fn watched_paths(files: &[String]) -> Result<Vec<PathBuf>, String> {
+    files.iter()
+        .map(|file| {
+            let path = Path::new(file);
+            let dir = match path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            };
+            let dir = dir.canonicalize().map_err(|err| format!("unable to watch {}: {}", dir.display(), err))?;
+
+            Ok(dir.join(path.file_name().unwrap_or_default()))
+        })
+        .collect()
+}
+
+/// Block until one of the paths changes and the changes settle for
+/// `debounce`. Empty if nothing changed before `timeout`.
+fn wait_for_change(
+    rx: &Receiver<notify::Result<notify::Event>>,
+    paths: &[PathBuf],
+    debounce: Duration,
+    timeout: Option<Duration>,
+) -> Result<Vec<PathBuf>, String> {
+    let started = Instant::now();
+    let mut changed: Vec<PathBuf> = Vec::new();
+
+    loop {
+        let wait = match (changed.is_empty(), timeout) {
+            (false, _) => debounce,
+            (true, Some(timeout)) => match timeout.checked_sub(started.elapsed()) {
+                Some(left) => left,
+                None => return Ok(changed),
+            },
+            (true, None) => Duration::from_secs(3600),
+        };
+
+        match rx.recv_timeout(wait) {
+            Ok(Ok(event)) if !matches!(event.kind, EventKind::Access(_)) => {
+                for path in event.paths.into_iter().filter(|path| paths.contains(path)) {
+                    if !changed.contains(&path) {
+                        changed.push(path);
+                    }
+                }
+            },
+            Ok(Ok(_)) => {},
+            Ok(Err(err)) => return Err(format!("unable to watch: {}", err)),
+            Err(RecvTimeoutError::Timeout) if !changed.is_empty() => return Ok(changed),
+            Err(RecvTimeoutError::Timeout) => {},
+            Err(RecvTimeoutError::Disconnected) => return Err("the watcher stopped".to_string()),
+        }
+    }
+}
+
+/// One line per part with its answer, timing and what changed since the
+/// previous answer.
+fn render(outcomes: &[Outcome], previous: &HashMap<String, String>) -> String {
+    outcomes.iter()
+        .map(|outcome| {
+            let answer = match &outcome.answer {
+                Ok(answer) => answer,
+                Err(err) => return format!("part {}: error: {}", outcome.part, err),
+            };
+
+            let timing = format!("{:?}", outcome.solution);
+            match previous.get(&format!("{}", outcome.part)) {
+                None => format!("part {}: {}  ({})", outcome.part, answer, timing),
+                Some(before) if before == answer => format!("part {}: {}  ({}, unchanged)", outcome.part, answer, timing),
+                Some(before) if !answer.contains('\n') && !before.contains('\n') => {
+                    format!("part {}: {}  ({}, was {})", outcome.part, answer, timing, before)
+                },
+                Some(before) => format!("part {}: changed  ({})\n{}", outcome.part, timing, diff(before, answer)),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Line by line diff of two answers, for the ones drawn with characters.
+fn diff(before: &str, after: &str) -> String {
+    let before = before.lines().collect::<Vec<&str>>();
+    let after = after.lines().collect::<Vec<&str>>();
+
+    (0..before.len().max(after.len()))
+        .flat_map(|idx| match (before.get(idx), after.get(idx)) {
+            (Some(old), Some(new)) if old == new => vec![format!("  {}", new)],
+            (old, new) => old.map(|old| format!("- {}", old)).into_iter()
+                .chain(new.map(|new| format!("+ {}", new)))
+                .collect(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn load_state(path: &Path) -> HashMap<String, String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_state(path: &Path, answers: &HashMap<String, String>) {
+    let result = path.parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(path, serde_json::to_string(answers).unwrap_or_default()));
+
+    if let Err(err) = result {
+        eprintln!("error: unable to write {}: {}", path.display(), err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+    use aoc::args::{day::Day, part::Part};
+
+    use super::Outcome;
+
+    fn outcome(part: Part, answer: &str) -> Outcome {
+        Outcome { part, answer: Ok(answer.to_string()), solution: Duration::from_millis(2) }
+    }
+
+    #[test]
+    fn render() {
+        let previous = HashMap::from([("1".to_string(), "24000".to_string()), ("2".to_string(), "45000".to_string())]);
+        let outcomes = vec![outcome(Part::Part1, "24000"), outcome(Part::Part2, "41000")];
+
+        assert_eq!(super::render(&outcomes, &previous), "part 1: 24000  (2ms, unchanged)\npart 2: 41000  (2ms, was 45000)");
+        assert_eq!(super::render(&outcomes[..1], &HashMap::new()), "part 1: 24000  (2ms)");
+
+        let failed = Outcome { part: Part::Part2, answer: Err("unable to read file: input/day01".to_string()), solution: Duration::ZERO };
+        assert_eq!(super::render(&[failed], &previous), "part 2: error: unable to read file: input/day01");
+    }
+
+    #[test]
+    fn render_drawing() {
+        let previous = HashMap::from([("2".to_string(), "#..\n.#.\n".to_string())]);
+        let output = super::render(&[outcome(Part::Part2, "#..\n##.\n")], &previous);
+
+        assert_eq!(output, "part 2: changed  (2ms)\n  #..\n- .#.\n+ ##.");
+    }
+
+    #[test]
+    fn diff() {
+        assert_eq!(super::diff("a\nb", "a\nb\nc"), "  a\n  b\n+ c");
+        assert_eq!(super::diff("a\nb", "b"), "- a\n+ b\n- b");
+    }
+
+    #[test]
+    fn candidates() {
+        assert_eq!(super::candidates("input", &Day::Day05, &[Part::Part1, Part::Part2], None), vec![
+            "input/day05", "input/day05.enc",
+            "input/day05-part1", "input/day05-part1.enc",
+            "input/day05-part2", "input/day05-part2.enc",
+        ]);
+        assert_eq!(super::candidates("input", &Day::Day05, &[Part::Part2], Some("example")), vec!["example", "example.enc"]);
+    }
+
+    #[test]
+    fn wait_for_change() {
+        let (tx, rx) = channel();
+        let watched = vec![PathBuf::from("/input/day01")];
+        let event = |path: &str| Ok(Event::new(EventKind::Any).add_path(PathBuf::from(path)));
+
+        tx.send(event("/input/day02")).unwrap();
+        tx.send(event("/input/day01")).unwrap();
+        tx.send(event("/input/day01")).unwrap();
+        let changed = super::wait_for_change(&rx, &watched, Duration::from_millis(10), Some(Duration::from_secs(1))).unwrap();
+        assert_eq!(changed, watched);
+
+        tx.send(event("/input/day02")).unwrap();
+        let changed = super::wait_for_change(&rx, &watched, Duration::from_millis(10), Some(Duration::from_millis(50))).unwrap();
+        assert!(changed.is_empty());
+
+        drop(tx);
+        assert_eq!(super::wait_for_change(&rx, &watched, Duration::ZERO, None), Err("the watcher stopped".to_string()));
+    }
+
+    #[test]
+    fn watch_file() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let files = vec![dir.join("day01").display().to_string()];
+        std::fs::write(&files[0], "1\n").unwrap();
+
+        let watched = super::watched_paths(&files).unwrap();
+        let (tx, rx) = channel();
+        let mut watcher = notify::recommended_watcher(tx).unwrap();
+        watcher.watch(watched[0].parent().unwrap(), RecursiveMode::NonRecursive).unwrap();
+
+        std::fs::write(dir.join("day02"), "2\n").unwrap();
+        std::fs::write(&files[0], "3\n").unwrap();
+        let changed = super::wait_for_change(&rx, &watched, Duration::from_millis(50), Some(Duration::from_secs(5))).unwrap();
+        assert_eq!(changed, watched);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn state() {
+        let path = std::env::temp_dir().join(format!("aoc2022-watch-state-{}", std::process::id())).join("day01");
+        let answers = HashMap::from([("1".to_string(), "24000".to_string())]);
+
+        assert!(super::load_state(&path).is_empty());
+        super::save_state(&path, &answers);
+        assert_eq!(super::load_state(&path), answers);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -7,6 +7,19 @@ pub enum Day {
     Day21, Day22, Day23, Day24, Day25,
 }
 
+impl Day {
+    /// Every day of the calendar, in order.
+    pub fn all() -> Vec<Day> {
+        vec![
+            Self::Day01, Self::Day02, Self::Day03, Self::Day04, Self::Day05,
+            Self::Day06, Self::Day07, Self::Day08, Self::Day09, Self::Day10,
+            Self::Day11, Self::Day12, Self::Day13, Self::Day14, Self::Day15,
+            Self::Day16, Self::Day17, Self::Day18, Self::Day19, Self::Day20,
+            Self::Day21, Self::Day22, Self::Day23, Self::Day24, Self::Day25,
+        ]
+    }
+}
+
 impl FromStr for Day {
     type Err = String;
 
@@ -111,6 +124,16 @@ mod tests {
         assert_eq!(Day::from_str("26"), Err("unable to parse day: 26".into()));
     }
 
+    #[test]
+    fn all() {
+        let days = Day::all();
+
+        assert_eq!(days.len(), 25);
+        for (idx, day) in days.iter().enumerate() {
+            assert_eq!(Day::from_str(&format!("{}", idx + 1)).as_ref(), Ok(day));
+        }
+    }
+
     #[test]
     fn display() {
         assert_eq!(format!("{}", Day::Day01), "01");
@@ -1,5 +1,6 @@
 pub mod day;
 pub mod part;
+pub mod year;
 
 use clap::Parser;
 
@@ -12,5 +13,37 @@ pub struct Args {
     #[arg(long, default_value_t = false)]
     pub time_it: bool,
     #[arg(long)]
-    pub input: Option<String>
+    pub input: Option<String>,
+    /// Solve with an external program instead of the built-in solver.
+    #[arg(long)]
+    pub external: Option<String>,
+    /// Seconds to wait for the external program.
+    #[arg(long, default_value_t = 30)]
+    pub external_timeout: u64,
+    /// Solve with scripts/dayNN.rhai instead of the built-in solver.
+    #[arg(long, default_value_t = false)]
+    pub script: bool,
+    /// Directory with solver plugins.
+    #[arg(long, default_value = "plugins")]
+    pub plugin_dir: String,
+    /// Solve with this plugin instead of the built-in solver. Without it,
+    /// plugins only solve the days that have no built-in solver.
+    #[arg(long)]
+    pub plugin: Option<String>,
+    /// Give up on the solver after this many seconds.
+    #[arg(long)]
+    pub timeout: Option<u64>,
+    /// Use the cached answer if the input and the build didn't change.
+    #[arg(long, default_value_t = false, conflicts_with = "no_cache")]
+    pub cached: bool,
+    /// Don't read or write the answer cache. Without it every answer is
+    /// stored in .cache/answers.
+    #[arg(long, default_value_t = false)]
+    pub no_cache: bool,
+    /// Use the inputs in input/<PROFILE>/ instead of input/.
+    #[arg(long)]
+    pub profile: Option<String>,
+    /// Solve with the input of every profile and check their recorded answers.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["profile", "input"])]
+    pub all_profiles: bool,
 }
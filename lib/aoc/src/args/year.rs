@@ -0,0 +1,78 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::Deserialize;
+
+/// Year of an Advent of Code event, the first one was in 2015.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash, Deserialize)]
+#[serde(try_from = "u16")]
+pub struct Year(u16);
+
+impl Year {
+    pub const FIRST: u16 = 2015;
+
+    /// Panics before 2015, for years known at compile time.
+    pub const fn new(year: u16) -> Self {
+        assert!(year >= Self::FIRST, "Advent of Code started in 2015");
+        Year(year)
+    }
+
+    pub fn number(&self) -> u16 {
+        self.0
+    }
+}
+
+/// The year this repository started with, its inputs can stay right in
+/// `input/`.
+impl Default for Year {
+    fn default() -> Self {
+        Year(2022)
+    }
+}
+
+impl TryFrom<u16> for Year {
+    type Error = String;
+
+    fn try_from(year: u16) -> Result<Self, Self::Error> {
+        match year {
+            Self::FIRST.. => Ok(Year(year)),
+            _ => Err(format!("there is no Advent of Code in {}", year)),
+        }
+    }
+}
+
+impl FromStr for Year {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse::<u16>().map_err(|_| format!("unable to parse year: {}", s))?;
+
+        Self::try_from(year)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Year;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Year::from_str("2022"), Ok(Year::new(2022)));
+        assert_eq!(Year::from_str("2015").map(|year| year.number()), Ok(2015));
+        assert_eq!(Year::from_str("2014"), Err("there is no Advent of Code in 2014".into()));
+        assert_eq!(Year::from_str("22nd"), Err("unable to parse year: 22nd".into()));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Year::default()), "2022");
+        assert!(Year::new(2023) > Year::default());
+    }
+}
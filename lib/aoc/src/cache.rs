@@ -0,0 +1,111 @@
+//! On-disk answer cache.
+//!
+//! Answers are stored one per file, keyed by the day, the part, the solver
+//! variant, the hash of the input and the build identifier, so any change to
+//! the input or the binary recomputes them.
+use std::path::PathBuf;
+
+use crate::args::{day::Day, part::Part};
+use crate::input::Input;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum CacheMode {
+    /// Don't touch the cache.
+    #[default]
+    Disabled,
+    /// Always compute, then store the answer.
+    Store,
+    /// Use the stored answer if there is one, compute and store it otherwise.
+    Use,
+}
+
+pub struct Cache {
+    dir: PathBuf,
+    build: String,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>, build: &str) -> Self {
+        Cache {
+            dir: dir.into(),
+            build: build.to_string(),
+        }
+    }
+
+    /// File name of the answer, `dayNN-partP-<hash>`.
+    pub fn key(&self, day: &Day, part: &Part, variant: &str, input: &Input) -> String {
+        let input_hash = hash(input.as_bytes());
+        let hash = hash(format!("{}\0{}\0{}\0{:016x}\0{}", day, part, variant, input_hash, self.build).as_bytes());
+
+        format!("day{:0>2}-part{}-{:016x}", day, part, hash)
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        std::fs::read_to_string(self.dir.join(key)).ok()
+    }
+
+    pub fn put(&self, key: &str, answer: &str) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.dir.join(key), answer)
+    }
+}
+
+/// Identifies the running binary, it changes with every rebuild.
+pub fn build_id() -> String {
+    let modified = std::env::current_exe()
+        .and_then(|path| path.metadata())
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+
+    format!("{}-{}", env!("CARGO_PKG_VERSION"), modified)
+}
+
+/// 64-bit FNV-1a, stable between builds unlike `DefaultHasher`.
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::args::{day::Day, part::Part};
+    use crate::input::Input;
+
+    use super::{hash, Cache};
+
+    #[test]
+    fn fnv() {
+        assert_eq!(hash(b""), 0xcbf29ce484222325);
+        assert_eq!(hash(b"a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn key() {
+        let cache = Cache::new("unused", "build");
+        let input = Input::from("1\n2\n");
+        let key = cache.key(&Day::Day01, &Part::Part1, "day01::Solution", &input);
+
+        assert!(key.starts_with("day01-part1-"), "{}", key);
+        assert_eq!(key, cache.key(&Day::Day01, &Part::Part1, "day01::Solution", &input));
+        assert_ne!(key, cache.key(&Day::Day01, &Part::Part2, "day01::Solution", &input));
+        assert_ne!(key, cache.key(&Day::Day01, &Part::Part1, "ScriptSolver", &input));
+        assert_ne!(key, cache.key(&Day::Day01, &Part::Part1, "day01::Solution", &Input::from("1\n")));
+        assert_ne!(key, Cache::new("unused", "other").key(&Day::Day01, &Part::Part1, "day01::Solution", &input));
+    }
+
+    #[test]
+    fn get_put() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir, "build");
+
+        assert_eq!(cache.get("day01-part1-x"), None);
+        cache.put("day01-part1-x", "42").unwrap();
+        assert_eq!(cache.get("day01-part1-x"), Some("42".to_string()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,102 @@
+//! Cooperative cancellation for long running solvers.
+//!
+//! The runner installs a token on the thread running the solver and cancels
+//! it when the solver runs out of time. Long loops can poll
+//! [`is_cancelled`] and give up early, their answer is thrown away anyway.
+//!
+//! ```
+//! use aoc::cancel::{self, Token};
+//!
+//! let token = Token::new();
+//! cancel::install(token.clone());
+//!
+//! let mut rows = 0;
+//! for _ in 0..4_000_001 {
+//!     if cancel::is_cancelled() { break }
+//!     rows += 1;
+//!     if rows == 10 { token.cancel() }
+//! }
+//!
+//! assert_eq!(rows, 10);
+//! ```
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Use `token` for [`is_cancelled`] calls on the current thread.
+pub fn install(token: Token) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(token));
+}
+
+/// Remove the token of the current thread.
+pub fn uninstall() {
+    CURRENT.with(|current| *current.borrow_mut() = None);
+}
+
+/// True if the token of the current thread was cancelled, false if the
+/// thread has no token.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| {
+        current.borrow().as_ref().map(|token| token.is_cancelled()).unwrap_or(false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::{install, is_cancelled, uninstall, Token};
+
+    #[test]
+    fn no_token() {
+        assert!(!is_cancelled());
+    }
+
+    #[test]
+    fn cancel() {
+        let token = Token::new();
+        install(token.clone());
+        assert!(!is_cancelled());
+
+        token.cancel();
+        assert!(token.is_cancelled());
+        assert!(is_cancelled());
+
+        uninstall();
+        assert!(!is_cancelled());
+    }
+
+    #[test]
+    fn per_thread() {
+        let token = Token::new();
+        install(token.clone());
+        token.cancel();
+
+        let other = thread::spawn(is_cancelled).join().unwrap();
+        assert!(!other);
+        assert!(is_cancelled());
+    }
+}
@@ -0,0 +1,476 @@
+//! Runner defaults from an `aoc.toml` file.
+//!
+//! The file is looked up from the current directory to the root, relative
+//! paths in it are relative to the file. `AOC_*` environment variables
+//! override the file, and command line flags override both.
+//!
+//! ```toml
+//! year = 2022
+//! input_dir = "input"
+//! fixture_dir = "lib/solution/tests/fixtures"
+//! template_dir = "template"
+//! format = "json"
+//! timeout = 60
+//! session = "file:.session"
+//! endpoint = "https://adventofcode.com"
+//! contact = "elf@example.com"
+//! key_file = ".aoc-key"
+//!
+//! [days.15]
+//! target_line = 10
+//! ```
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::args::{day::Day, year::Year};
+
+pub const FILENAME: &str = "aoc.toml";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Only the answer.
+    #[default]
+    Plain,
+    /// The answer, the timings and where they come from as JSON.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Self::Plain),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown output format: {}", s)),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Plain => write!(f, "plain"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Where the adventofcode.com session token comes from, `env:NAME` or
+/// `file:PATH`.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub enum SessionSource {
+    Env(String),
+    File(PathBuf),
+}
+
+impl SessionSource {
+    pub fn token(&self) -> Option<String> {
+        let token = match self {
+            Self::Env(name) => std::env::var(name).ok()?,
+            Self::File(path) => std::fs::read_to_string(path).ok()?,
+        };
+
+        Some(token.trim().to_string()).filter(|token| !token.is_empty())
+    }
+}
+
+impl Default for SessionSource {
+    fn default() -> Self {
+        Self::Env("AOC_SESSION".to_string())
+    }
+}
+
+impl FromStr for SessionSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some(("env", name)) if !name.is_empty() => Ok(Self::Env(name.to_string())),
+            Some(("file", path)) if !path.is_empty() => Ok(Self::File(path.into())),
+            _ => Err(format!("session should be env:NAME or file:PATH, not {}", s)),
+        }
+    }
+}
+
+impl TryFrom<String> for SessionSource {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Env(name) => write!(f, "env:{}", name),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+        }
+    }
+}
+
+/// Command line flags that take precedence over the configuration.
+#[derive(clap::Args, Debug, Default, Clone)]
+pub struct Overrides {
+    /// Configuration file to use instead of the aoc.toml found from the current directory up.
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+    /// Year of the puzzles.
+    #[arg(long, global = true)]
+    pub year: Option<Year>,
+    /// Directory with the puzzle inputs.
+    #[arg(long, global = true)]
+    pub input_dir: Option<PathBuf>,
+    /// Directory with the example inputs.
+    #[arg(long, global = true)]
+    pub fixture_dir: Option<PathBuf>,
+    #[arg(long, global = true, value_enum)]
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// File the values come from, `None` when there is no aoc.toml.
+    #[serde(skip)]
+    pub file: Option<PathBuf>,
+    pub year: Year,
+    /// Inputs of every year, see [`Config::year_input_dir`].
+    pub input_dir: PathBuf,
+    /// Where the scaffolding writes the example of a new day.
+    pub fixture_dir: PathBuf,
+    /// Templates of the scaffolding.
+    pub template_dir: PathBuf,
+    pub format: OutputFormat,
+    /// Seconds before giving up on a solver.
+    pub timeout: Option<u64>,
+    pub session: SessionSource,
+    /// Site the puzzle inputs are downloaded from.
+    pub endpoint: String,
+    /// Who to reach about the automated requests to the endpoint, an email
+    /// or a repository URL. It goes in their User-Agent.
+    pub contact: Option<String>,
+    /// Key of the encrypted inputs, `AOC_INPUT_KEY` takes precedence.
+    pub key_file: PathBuf,
+    /// Parameters of the solvers, by day.
+    pub days: BTreeMap<String, toml::Table>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            file: None,
+            year: Year::default(),
+            input_dir: "input".into(),
+            fixture_dir: "lib/solution/tests/fixtures".into(),
+            template_dir: "template".into(),
+            format: OutputFormat::default(),
+            timeout: None,
+            session: SessionSource::default(),
+            endpoint: "https://adventofcode.com".to_string(),
+            contact: None,
+            key_file: ".aoc-key".into(),
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Configuration from the file, the environment and the flags.
+    pub fn load(overrides: &Overrides) -> Result<Config, String> {
+        let file = match &overrides.config {
+            Some(path) => Some(path.clone()),
+            None => match std::env::var_os("AOC_CONFIG") {
+                Some(path) => Some(path.into()),
+                None => std::env::current_dir().ok().and_then(|dir| Self::find(&dir)),
+            },
+        };
+
+        let config = match file {
+            Some(path) => Self::from_file(&path)?,
+            None => Config::default(),
+        };
+
+        Ok(config.with_env(|name| std::env::var(name).ok())?.with_overrides(overrides))
+    }
+
+    /// First aoc.toml in `dir` or one of its parents.
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(FILENAME))
+            .find(|path| path.is_file())
+    }
+
+    pub fn from_file(path: &Path) -> Result<Config, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+        let mut config = Self::parse(&content)
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        config.input_dir = base.join(&config.input_dir);
+        config.fixture_dir = base.join(&config.fixture_dir);
+        config.template_dir = base.join(&config.template_dir);
+        config.key_file = base.join(&config.key_file);
+        if let SessionSource::File(session) = &config.session {
+            config.session = SessionSource::File(base.join(session));
+        }
+        config.file = Some(path.to_path_buf());
+
+        Ok(config)
+    }
+
+    pub fn parse(content: &str) -> Result<Config, String> {
+        let config: Config = toml::from_str(content).map_err(|err| err.message().to_string())?;
+
+        if let Some(day) = config.days.keys().find(|day| Day::from_str(day).is_err()) {
+            return Err(format!("unknown day in [days]: {}", day));
+        }
+
+        Ok(config)
+    }
+
+    /// Apply the `AOC_*` variables, `vars` reads one of them.
+    pub fn with_env(mut self, vars: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+        if let Some(dir) = vars("AOC_INPUT_DIR") {
+            self.input_dir = dir.into();
+        }
+        if let Some(dir) = vars("AOC_FIXTURE_DIR") {
+            self.fixture_dir = dir.into();
+        }
+        if let Some(dir) = vars("AOC_TEMPLATE_DIR") {
+            self.template_dir = dir.into();
+        }
+        if let Some(format) = vars("AOC_FORMAT") {
+            self.format = format.parse()?;
+        }
+        if let Some(timeout) = vars("AOC_TIMEOUT") {
+            self.timeout = Some(timeout.parse().map_err(|_| format!("AOC_TIMEOUT is not a number of seconds: {}", timeout))?);
+        }
+        if let Some(session) = vars("AOC_SESSION_SOURCE") {
+            self.session = session.parse()?;
+        }
+        if let Some(endpoint) = vars("AOC_ENDPOINT") {
+            self.endpoint = endpoint;
+        }
+        if let Some(contact) = vars("AOC_CONTACT") {
+            self.contact = Some(contact);
+        }
+        if let Some(year) = vars("AOC_YEAR") {
+            self.year = year.parse()?;
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_overrides(mut self, overrides: &Overrides) -> Config {
+        if let Some(year) = overrides.year {
+            self.year = year;
+        }
+        if let Some(dir) = &overrides.input_dir {
+            self.input_dir = dir.clone();
+        }
+        if let Some(dir) = &overrides.fixture_dir {
+            self.fixture_dir = dir.clone();
+        }
+        if let Some(format) = overrides.format {
+            self.format = format;
+        }
+
+        self
+    }
+
+    /// Inputs and answers of the selected year, `input/2023`. The ones of
+    /// the default year are right in `input`. It only depends on the
+    /// configuration, so it's the same for every filesystem.
+    pub fn year_input_dir(&self) -> PathBuf {
+        match self.year == Year::default() {
+            true => self.input_dir.clone(),
+            false => self.input_dir.join(self.year.to_string()),
+        }
+    }
+
+    /// Parameters of the day, strings without their quotes.
+    pub fn params(&self, day: &Day) -> Vec<(String, String)> {
+        self.days.iter()
+            .filter(|(key, _)| Day::from_str(key).as_ref() == Ok(day))
+            .flat_map(|(_, table)| table.iter())
+            .map(|(name, value)| match value {
+                toml::Value::String(value) => (name.clone(), value.clone()),
+                value => (name.clone(), value.to_string()),
+            })
+            .collect()
+    }
+}
+
+/// One `key = value` per line, like the file but with every value set.
+impl Display for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => writeln!(f, "# {}", file.display())?,
+            None => writeln!(f, "# no {}, defaults", FILENAME)?,
+        }
+        writeln!(f, "year = {}", self.year)?;
+        writeln!(f, "input_dir = {:?}", self.input_dir.display().to_string())?;
+        writeln!(f, "fixture_dir = {:?}", self.fixture_dir.display().to_string())?;
+        writeln!(f, "template_dir = {:?}", self.template_dir.display().to_string())?;
+        writeln!(f, "format = \"{}\"", self.format)?;
+        match self.timeout {
+            Some(timeout) => writeln!(f, "timeout = {}", timeout)?,
+            None => writeln!(f, "# timeout = none")?,
+        }
+        write!(f, "session = \"{}\"", self.session)?;
+        if self.session.token().is_none() {
+            write!(f, " # no token")?;
+        }
+        write!(f, "\nendpoint = {:?}", self.endpoint)?;
+        match &self.contact {
+            Some(contact) => write!(f, "\ncontact = {:?}", contact)?,
+            None => write!(f, "\n# contact = none")?,
+        }
+        write!(f, "\nkey_file = {:?}", self.key_file.display().to_string())?;
+
+        for day in Day::all() {
+            let tables = self.days.iter()
+                .filter(|(key, _)| Day::from_str(key).as_ref() == Ok(&day))
+                .map(|(_, table)| table)
+                .collect::<Vec<&toml::Table>>();
+            if tables.is_empty() {
+                continue;
+            }
+
+            write!(f, "\n\n[days.{}]", day)?;
+            for (name, value) in tables.into_iter().flatten() {
+                write!(f, "\n{} = {}", name, value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    use crate::args::{day::Day, year::Year};
+
+    use super::{Config, OutputFormat, Overrides, SessionSource};
+
+    const CONTENT: &str = r#"
+        input_dir = "inputs/2022"
+        format = "json"
+        timeout = 60
+        session = "file:.session"
+
+        [days.15]
+        target_line = 10
+        label = "row"
+    "#;
+
+    #[test]
+    fn parse() {
+        let config = Config::parse(CONTENT).unwrap();
+
+        assert_eq!(config.input_dir, PathBuf::from("inputs/2022"));
+        assert_eq!(config.fixture_dir, Config::default().fixture_dir);
+        assert_eq!(config.format, OutputFormat::Json);
+        assert_eq!(config.timeout, Some(60));
+        assert_eq!(config.session, SessionSource::File(".session".into()));
+        assert_eq!(config.params(&Day::Day15), vec![
+            ("label".to_string(), "row".to_string()),
+            ("target_line".to_string(), "10".to_string()),
+        ]);
+        assert!(config.params(&Day::Day01).is_empty());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Config::parse("inputs = \"x\"").unwrap_err().contains("unknown field"));
+        assert!(Config::parse("format = \"xml\"").is_err());
+        assert!(Config::parse("session = \"cookie\"").unwrap_err().contains("env:NAME or file:PATH"));
+        assert_eq!(Config::parse("[days.26]\nx = 1").unwrap_err(), "unknown day in [days]: 26");
+        assert_eq!(Config::parse("year = 2014").unwrap_err(), "there is no Advent of Code in 2014");
+    }
+
+    #[test]
+    fn precedence() {
+        let env = HashMap::from([("AOC_INPUT_DIR", "from-env"), ("AOC_TIMEOUT", "5"), ("AOC_FORMAT", "plain"), ("AOC_ENDPOINT", "http://localhost:8000"), ("AOC_CONTACT", "elf@example.com")]);
+        let overrides = Overrides { input_dir: Some("from-flag".into()), ..Default::default() };
+
+        let config = Config::parse(CONTENT).unwrap()
+            .with_env(|name| env.get(name).map(|value| value.to_string())).unwrap();
+        assert_eq!(config.input_dir, PathBuf::from("from-env"));
+        assert_eq!(config.timeout, Some(5));
+        assert_eq!(config.format, OutputFormat::Plain);
+        assert_eq!(config.endpoint, "http://localhost:8000");
+        assert_eq!(config.contact, Some("elf@example.com".to_string()));
+
+        let config = config.with_overrides(&overrides);
+        assert_eq!(config.input_dir, PathBuf::from("from-flag"));
+
+        let err = Config::default().with_env(|_| Some("soon".to_string())).unwrap_err();
+        assert_eq!(err, "unknown output format: soon");
+    }
+
+    #[test]
+    fn from_file() {
+        let dir = std::env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("lib/solution")).unwrap();
+        std::fs::write(dir.join("aoc.toml"), CONTENT).unwrap();
+        std::fs::write(dir.join(".session"), "cookie\n").unwrap();
+
+        let path = Config::find(&dir.join("lib/solution")).unwrap();
+        assert_eq!(path, dir.join("aoc.toml"));
+
+        let config = Config::from_file(&path).unwrap();
+        assert_eq!(config.file, Some(path));
+        assert_eq!(config.input_dir, dir.join("inputs/2022"));
+        assert_eq!(config.template_dir, dir.join("template"));
+        assert_eq!(config.key_file, dir.join(".aoc-key"));
+        assert_eq!(config.session.token(), Some("cookie".to_string()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+        assert_eq!(Config::find(Path::new("/")), None);
+    }
+
+    #[test]
+    fn display() {
+        let config = Config::parse(CONTENT).unwrap();
+        let output = format!("{}", config);
+
+        assert!(output.starts_with("# no aoc.toml, defaults\nyear = 2022\ninput_dir = \"inputs/2022\"\n"), "{}", output);
+        assert!(output.contains("\ntimeout = 60\n"), "{}", output);
+        assert!(output.contains("session = \"file:.session\" # no token\nendpoint = \"https://adventofcode.com\"\n# contact = none\n"), "{}", output);
+        assert!(output.ends_with("[days.15]\nlabel = \"row\"\ntarget_line = 10"), "{}", output);
+    }
+
+    #[test]
+    fn year() {
+        let env = HashMap::from([("AOC_YEAR", "2023")]);
+        let config = Config::parse("year = 2021").unwrap();
+        assert_eq!(config.year, Year::new(2021));
+
+        let config = config.with_env(|name| env.get(name).map(|value| value.to_string())).unwrap();
+        assert_eq!(config.year, Year::new(2023));
+
+        let config = config.with_overrides(&Overrides { year: Some(Year::new(2024)), ..Default::default() });
+        assert_eq!(config.year, Year::new(2024));
+    }
+
+    #[test]
+    fn year_input_dir() {
+        let config = Config { input_dir: "inputs".into(), ..Default::default() };
+
+        assert_eq!(config.year_input_dir(), PathBuf::from("inputs"));
+        assert_eq!(Config { year: Year::new(2023), ..config.clone() }.year_input_dir(), PathBuf::from("inputs/2023"));
+    }
+}
@@ -0,0 +1,264 @@
+//! Puzzle inputs encrypted with ChaCha20-Poly1305, safe to commit.
+//!
+//! `input/day01.enc` is the encrypted `input/day01`, as text: a header line,
+//! the nonce and the ciphertext in hex. The file name is authenticated too,
+//! so an encrypted input can't pass for another day.
+//!
+//! ```
+//! use aoc::encrypted::{self, Key};
+//!
+//! let key = Key::generate();
+//! let encrypted = encrypted::encrypt(&key, "day01", "1000\n2000\n");
+//!
+//! assert_eq!(encrypted::decrypt(&key, "day01", &encrypted), Ok("1000\n2000\n".to_string()));
+//! assert!(encrypted::decrypt(&key, "day02", &encrypted).is_err());
+//! ```
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+use crate::input::Input;
+use crate::io::{Error, Filesystem};
+
+pub const EXTENSION: &str = "enc";
+/// Variable with the key in hex, it takes precedence over the key file.
+pub const KEY_VARIABLE: &str = "AOC_INPUT_KEY";
+
+const HEADER: &str = "aoc-encrypted-v1";
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let bytes = from_hex(hex.trim()).ok_or("the key is not hexadecimal")?;
+        let bytes: [u8; 32] = bytes.try_into().map_err(|_| "the key should be 32 bytes long")?;
+
+        Ok(Key(bytes))
+    }
+
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.0)
+    }
+
+    /// Key from `AOC_INPUT_KEY`, or from the key file. `None` if neither
+    /// is set.
+    pub fn load(key_file: &Path) -> Result<Option<Self>, String> {
+        if let Ok(hex) = std::env::var(KEY_VARIABLE) {
+            return Self::from_hex(&hex).map(Some).map_err(|err| format!("{}: {}", KEY_VARIABLE, err));
+        }
+
+        match std::fs::read_to_string(key_file) {
+            Ok(hex) => Self::from_hex(&hex).map(Some).map_err(|err| format!("{}: {}", key_file.display(), err)),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// Don't print the key by accident.
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+/// Encrypt `content`, `name` is the name of the file without `.enc`.
+pub fn encrypt(key: &Key, name: &str, content: &str) -> String {
+    let cipher = ChaCha20Poly1305::new(&key.0.into());
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher.encrypt(&nonce, Payload { msg: content.as_bytes(), aad: name.as_bytes() })
+        .expect("encryption of an input in memory can't fail");
+
+    format!("{}\n{}\n{}\n", HEADER, to_hex(&nonce), to_hex(&ciphertext))
+}
+
+pub fn decrypt(key: &Key, name: &str, encrypted: &str) -> Result<String, String> {
+    let mut lines = encrypted.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(format!("{} is not an encrypted input", name));
+    }
+
+    let nonce = lines.next().and_then(from_hex).filter(|nonce| nonce.len() == 12);
+    let ciphertext = lines.next().and_then(from_hex);
+    let (nonce, ciphertext) = match (nonce, ciphertext) {
+        (Some(nonce), Some(ciphertext)) => (nonce, ciphertext),
+        _ => return Err(format!("{} is damaged", name)),
+    };
+
+    let cipher = ChaCha20Poly1305::new(&key.0.into());
+    let plaintext = cipher.decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: name.as_bytes() })
+        .map_err(|_| format!("unable to decrypt {}, wrong key or damaged file", name))?;
+
+    String::from_utf8(plaintext).map_err(|_| format!("{} is not text", name))
+}
+
+/// Reads `path.enc` when `path` itself doesn't exist, plain files are read
+/// as they are.
+pub struct EncryptedFilesystem<F: Filesystem> {
+    inner: F,
+    key_file: Option<PathBuf>,
+    key: OnceLock<Result<Option<Key>, String>>,
+}
+
+impl<F: Filesystem> EncryptedFilesystem<F> {
+    /// Without a key, encrypted files exist but can't be read.
+    pub fn new(inner: F, key: Option<Key>) -> Self {
+        EncryptedFilesystem { inner, key_file: None, key: OnceLock::from(Ok(key)) }
+    }
+
+    /// Load the key with [`Key::load`] on the first encrypted read, so a
+    /// broken key file only matters when there is something to decrypt.
+    pub fn with_key_file(inner: F, key_file: &Path) -> Self {
+        EncryptedFilesystem { inner, key_file: Some(key_file.to_path_buf()), key: OnceLock::new() }
+    }
+
+    fn key(&self) -> Result<Option<&Key>, String> {
+        let key = self.key.get_or_init(|| match &self.key_file {
+            Some(path) => Key::load(path),
+            None => Ok(None),
+        });
+
+        key.as_ref().map(Option::as_ref).map_err(String::clone)
+    }
+
+    fn encrypted_path(path: &str) -> String {
+        format!("{}.{}", path, EXTENSION)
+    }
+}
+
+impl<F: Filesystem> Filesystem for EncryptedFilesystem<F> {
+    fn path_exists(&self, path: &str) -> bool {
+        self.inner.path_exists(path) || self.inner.path_exists(&Self::encrypted_path(path))
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        let encrypted_path = Self::encrypted_path(path);
+        if self.inner.path_exists(path) || !self.inner.path_exists(&encrypted_path) {
+            return self.inner.read_file(path);
+        }
+
+        let key = self.key().map_err(Error::Encrypted)?.ok_or_else(|| Error::Encrypted(
+            format!("{} needs a key, set {} or create the key file", encrypted_path, KEY_VARIABLE)
+        ))?;
+        let name = Path::new(path).file_name().and_then(|name| name.to_str()).unwrap_or(path);
+        let encrypted = self.inner.read_file(&encrypted_path)?;
+
+        decrypt(key, name, encrypted.as_str())
+            .map(Input::from)
+            .map_err(Error::Encrypted)
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        self.inner.sub_dirs(path)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::Input;
+    use crate::io::{Error, Filesystem, InMemoryFilesystem};
+
+    use super::{decrypt, encrypt, EncryptedFilesystem, Key};
+
+    #[test]
+    fn key() {
+        let key = Key::generate();
+
+        assert_eq!(Key::from_hex(&key.to_hex()), Ok(key.clone()));
+        assert_ne!(Key::generate(), key);
+        assert_eq!(format!("{:?}", key), "Key(..)");
+        assert_eq!(Key::from_hex("abcd"), Err("the key should be 32 bytes long".to_string()));
+        assert_eq!(Key::from_hex("xyz"), Err("the key is not hexadecimal".to_string()));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, "day01", "secret\n");
+
+        assert!(encrypted.starts_with("aoc-encrypted-v1\n"));
+        assert!(!encrypted.contains("secret"));
+        assert_ne!(encrypted, encrypt(&key, "day01", "secret\n"));
+        assert_eq!(decrypt(&key, "day01", &encrypted), Ok("secret\n".to_string()));
+    }
+
+    #[test]
+    fn decrypt_errors() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, "day01", "1\n2\n");
+
+        assert_eq!(decrypt(&Key::generate(), "day01", &encrypted), Err("unable to decrypt day01, wrong key or damaged file".to_string()));
+        assert_eq!(decrypt(&key, "day01", "1\n2\n"), Err("day01 is not an encrypted input".to_string()));
+        assert_eq!(decrypt(&key, "day01", "aoc-encrypted-v1\n00\n"), Err("day01 is damaged".to_string()));
+
+        let mut tampered = encrypted.trim_end().to_string();
+        let last = tampered.pop().unwrap();
+        tampered.push(if last == '0' { '1' } else { '0' });
+        assert_eq!(decrypt(&key, "day01", &tampered), Err("unable to decrypt day01, wrong key or damaged file".to_string()));
+    }
+
+    #[test]
+    fn filesystem() {
+        let key = Key::generate();
+        let files = InMemoryFilesystem::new()
+            .with_file("input/day01.enc", encrypt(&key, "day01", "secret\n"))
+            .with_file("input/day02", "plain\n");
+        let fs = EncryptedFilesystem::new(files, Some(key));
+
+        assert!(fs.path_exists("input/day01"));
+        assert!(fs.path_exists("input/day02"));
+        assert!(!fs.path_exists("input/day03"));
+        assert_eq!(fs.read_file("input/day01"), Ok(Input::from("secret\n")));
+        assert_eq!(fs.read_file("input/day02"), Ok(Input::from("plain\n")));
+        assert_eq!(fs.read_file("input/day03"), Err(Error::ReadError("input/day03".to_string())));
+    }
+
+    #[test]
+    fn filesystem_without_key() {
+        let key = Key::generate();
+        let files = InMemoryFilesystem::new()
+            .with_file("input/day01.enc", encrypt(&key, "day01", "secret\n"));
+        let fs = EncryptedFilesystem::new(files, None);
+
+        assert!(fs.path_exists("input/day01"));
+        let err = fs.read_file("input/day01").unwrap_err();
+        assert_eq!(format!("{}", err), "input/day01.enc needs a key, set AOC_INPUT_KEY or create the key file");
+    }
+
+    #[test]
+    fn filesystem_with_broken_key_file() {
+        let path = std::env::temp_dir().join(format!("aoc-broken-key-{}", std::process::id()));
+        std::fs::write(&path, "xyz\n").unwrap();
+        let files = InMemoryFilesystem::new()
+            .with_file("input/day01.enc", encrypt(&Key::generate(), "day01", "secret\n"))
+            .with_file("input/day02", "plain\n");
+        let fs = EncryptedFilesystem::with_key_file(files, &path);
+
+        assert_eq!(fs.read_file("input/day02"), Ok(Input::from("plain\n")));
+        let err = fs.read_file("input/day01").unwrap_err();
+        assert_eq!(format!("{}", err), format!("{}: the key is not hexadecimal", path.display()));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{cache, Solver};
+use crate::args::{day::Day, part::Part};
+use crate::input::Input;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptyCommand,
+    InvalidCommand(String),
+    SpawnError(String),
+    Timeout(Duration),
+    Failed(Option<i32>, String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyCommand => write!(f, "external command is empty"),
+            Self::InvalidCommand(command) => write!(f, "external command has an unclosed quote: {}", command),
+            Self::SpawnError(err) => write!(f, "unable to start external command: {}", err),
+            Self::Timeout(timeout) => write!(f, "external command timed out after {:?}", timeout),
+            Self::Failed(Some(code), stderr) => write!(f, "external command failed with exit code {}: {}", code, stderr.trim_end()),
+            Self::Failed(None, stderr) => write!(f, "external command was terminated: {}", stderr.trim_end()),
+        }
+    }
+}
+
+/// Solver backed by an external program.
+///
+/// The program is called once per part as `<command> <day> <part>`, with the
+/// same values in the `AOC_DAY` and `AOC_PART` environment variables. The
+/// puzzle input is written to its stdin and whatever it prints on stdout
+/// (without trailing whitespace) is the answer.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use aoc::Solver;
+/// use aoc::args::day::Day;
+/// use aoc::input::Input;
+///
+/// let mut solver = aoc::external::ExternalSolver::new("python3 day01.py", Day::Day01)
+///     .with_timeout(Duration::from_secs(5));
+/// solver.read_input(&Input::from("1000\n2000\n"));
+///
+/// let part1_solution = solver.part1();
+/// ```
+pub struct ExternalSolver {
+    command: String,
+    day: Day,
+    timeout: Duration,
+    input: Input,
+}
+
+impl Solver for ExternalSolver {
+    fn name(&self) -> &'static str {
+        "ExternalSolver"
+    }
+
+    fn variant(&self) -> Option<String> {
+        Some(self.fingerprint())
+    }
+
+    fn read_input(&mut self, input: &Input) {
+        self.input = input.clone();
+    }
+
+    fn part1(&mut self) -> String {
+        match self.execute(&Part::Part1) {
+            Ok(answer) => answer,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn part2(&mut self) -> String {
+        match self.execute(&Part::Part2) {
+            Ok(answer) => answer,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn solve(&mut self, part: &Part) -> Result<String, String> {
+        self.execute(part).map_err(|err| format!("{}", err))
+    }
+}
+
+impl ExternalSolver {
+    /// New solver for `day`. `command` is split like a shell would, see
+    /// [`split_command`], the first word is the program and the rest are
+    /// passed as leading arguments.
+    pub fn new(command: &str, day: Day) -> Self {
+        Self {
+            command: command.to_string(),
+            day,
+            timeout: DEFAULT_TIMEOUT,
+            input: Input::default(),
+        }
+    }
+
+    /// Kill the program if it does not finish within `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The command with the hashes of the program and of the files in its
+    /// arguments, like the script of `python3 day01.py`, so the answers
+    /// change when any of them does.
+    pub fn fingerprint(&self) -> String {
+        let words = split_command(&self.command).unwrap_or_default();
+        let hashes = words.iter()
+            .enumerate()
+            .filter_map(|(idx, word)| match idx {
+                0 => resolve_program(word),
+                _ => Some(PathBuf::from(word)).filter(|path| path.is_file()),
+            })
+            .filter_map(|path| std::fs::read(path).ok())
+            .map(|bytes| format!("{:016x}", cache::hash(&bytes)))
+            .collect::<Vec<String>>();
+
+        format!("{}:{}", self.command, hashes.join(":"))
+    }
+
+    pub fn execute(&self, part: &Part) -> Result<String, Error> {
+        let command = split_command(&self.command)?;
+        let (program, args) = command.split_first().ok_or(Error::EmptyCommand)?;
+
+        let mut child = Command::new(program)
+            .args(args)
+            .arg(format!("{}", self.day))
+            .arg(format!("{}", part))
+            .env("AOC_DAY", format!("{}", self.day))
+            .env("AOC_PART", format!("{}", part))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| Error::SpawnError(format!("{}: {}", program, err)))?;
+
+        // Feed and drain the pipes on their own threads, otherwise a chatty
+        // program can block on a full pipe and we would report a timeout.
+        let mut stdin = child.stdin.take().unwrap();
+        let input = self.input.clone();
+        thread::spawn(move || {
+            // The program may exit without reading everything, that's fine.
+            let _ = stdin.write_all(input.as_bytes());
+        });
+
+        let stdout = reader(child.stdout.take().unwrap());
+        let stderr = reader(child.stderr.take().unwrap());
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) if Instant::now() >= deadline => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(Error::Timeout(self.timeout));
+                },
+                Ok(None) => thread::sleep(POLL_INTERVAL),
+                Err(err) => return Err(Error::SpawnError(format!("{}: {}", program, err))),
+            }
+        };
+
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+
+        if !status.success() {
+            return Err(Error::Failed(status.code(), stderr));
+        }
+
+        Ok(stdout.trim_end().to_string())
+    }
+}
+
+/// Words of a command line, with the quotes and backslashes of a POSIX
+/// shell: `python3 'my solver.py'` is `["python3", "my solver.py"]`.
+pub fn split_command(command: &str) -> Result<Vec<String>, Error> {
+    shell_words::split(command).map_err(|_| Error::InvalidCommand(command.to_string()))
+}
+
+/// File run for `program`: the path itself when it has a directory,
+/// otherwise the first match in `PATH`.
+fn resolve_program(program: &str) -> Option<PathBuf> {
+    let path = Path::new(program);
+    if path.components().count() > 1 {
+        return Some(path.to_path_buf()).filter(|path| path.is_file());
+    }
+
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(program))
+        .find(|path| path.is_file())
+}
+
+fn reader(mut source: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buffer = String::new();
+        let _ = source.read_to_string(&mut buffer);
+        buffer
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::Solver;
+    use crate::args::{day::Day, part::Part};
+    use crate::input::Input;
+
+    use super::{Error, ExternalSolver};
+
+    const SCRIPT: &str = "sh tests/fixtures/external_solver.sh";
+
+    fn input() -> Input {
+        Input::from("10\n20\n30\n")
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(ExternalSolver::new(SCRIPT, Day::Day01).name(), "ExternalSolver")
+    }
+
+    #[test]
+    fn part1() {
+        let mut solver = ExternalSolver::new(SCRIPT, Day::Day01);
+        solver.read_input(&input());
+
+        assert_eq!(solver.part1(), "3".to_string());
+    }
+
+    #[test]
+    fn part2() {
+        let mut solver = ExternalSolver::new(SCRIPT, Day::Day01);
+        solver.read_input(&input());
+
+        assert_eq!(solver.part2(), "60".to_string());
+    }
+
+    #[test]
+    fn solve() {
+        let mut solver = ExternalSolver::new(SCRIPT, Day::Day01);
+        solver.read_input(&input());
+
+        assert_eq!(solver.solve(&Part::Part1), Ok("3".to_string()));
+        assert_eq!(solver.solve(&Part::Part2), Ok("60".to_string()));
+    }
+
+    #[test]
+    fn timeout() {
+        let timeout = Duration::from_millis(100);
+        let solver = ExternalSolver::new(SCRIPT, Day::Day02).with_timeout(timeout);
+
+        assert_eq!(solver.execute(&Part::Part1), Err(Error::Timeout(timeout)));
+    }
+
+    #[test]
+    fn failed() {
+        let solver = ExternalSolver::new(SCRIPT, Day::Day03);
+
+        assert_eq!(solver.execute(&Part::Part1), Err(Error::Failed(Some(3), "something went wrong\n".into())));
+    }
+
+    #[test]
+    fn failed_message() {
+        let mut solver = ExternalSolver::new(SCRIPT, Day::Day03);
+
+        assert_eq!(
+            solver.solve(&Part::Part1),
+            Err("external command failed with exit code 3: something went wrong".into()),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn failed_part1() {
+        ExternalSolver::new(SCRIPT, Day::Day03).part1();
+    }
+
+    #[test]
+    fn empty_command() {
+        let solver = ExternalSolver::new("  ", Day::Day01);
+
+        assert_eq!(solver.execute(&Part::Part1), Err(Error::EmptyCommand));
+    }
+
+    #[test]
+    fn quoted_command() {
+        let solver = ExternalSolver::new(r#"sh -c 'cat > /dev/null; echo "day $1 part $2"' solver"#, Day::Day01);
+
+        assert_eq!(solver.execute(&Part::Part2), Ok("day 01 part 2".to_string()));
+    }
+
+    #[test]
+    fn split_command() {
+        assert_eq!(super::split_command("python3 'my solver.py' --fast"), Ok(vec!["python3".to_string(), "my solver.py".to_string(), "--fast".to_string()]));
+        assert_eq!(super::split_command(r#"run "a \"b\"" c\ d"#), Ok(vec!["run".to_string(), "a \"b\"".to_string(), "c d".to_string()]));
+        assert_eq!(super::split_command("  "), Ok(vec![]));
+        assert_eq!(super::split_command("python3 'day01.py"), Err(Error::InvalidCommand("python3 'day01.py".to_string())));
+    }
+
+    #[test]
+    fn fingerprint() {
+        let script = std::env::temp_dir().join(format!("aoc-external-{}.sh", std::process::id()));
+        std::fs::write(&script, "echo 1\n").unwrap();
+        let solver = ExternalSolver::new(&format!("sh {} --fast", script.display()), Day::Day01);
+        let before = solver.fingerprint();
+
+        assert_eq!(before.split(':').count(), 3, "{}", before);
+        assert_eq!(solver.fingerprint(), before);
+        std::fs::write(&script, "echo 2\n").unwrap();
+        assert_ne!(solver.fingerprint(), before);
+
+        std::fs::remove_file(script).unwrap();
+    }
+
+    #[test]
+    fn missing_program() {
+        let solver = ExternalSolver::new("__program_is_not_here__", Day::Day01);
+
+        assert!(matches!(solver.execute(&Part::Part1), Err(Error::SpawnError(_))));
+    }
+}
@@ -91,7 +91,7 @@ mod tests {
             Vec2D::new(2, 2),
             |pos| if pos.x == 0 || pos.y == 0 { '#' } else { '.' },
         );
-        let expected = vec![
+        let expected = [
             "..#..",
             "..#..",
             "#####",
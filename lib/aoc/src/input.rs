@@ -0,0 +1,151 @@
+//! Puzzle input in one contiguous buffer.
+//!
+//! Solvers borrow lines, records and bytes from it instead of getting an
+//! owned `String` for every line.
+//!
+//! ```
+//! use aoc::input::Input;
+//!
+//! let input = Input::from("1000\n2000\n\n4000\n");
+//!
+//! assert_eq!(input.lines().collect::<Vec<&str>>(), vec!["1000", "2000", "", "4000"]);
+//! assert_eq!(input.records().collect::<Vec<&str>>(), vec!["1000\n2000", "4000"]);
+//! assert_eq!(input.byte_lines().next(), Some(&b"1000"[..]));
+//! ```
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Input {
+    buffer: String,
+}
+
+impl Input {
+    pub fn new(buffer: String) -> Self {
+        Input { buffer }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer.as_bytes()
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Lines without their `\n` or `\r\n` ending, a trailing empty line is
+    /// not reported.
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.buffer.lines()
+    }
+
+    /// Same as [`Input::lines`] but as bytes.
+    pub fn byte_lines(&self) -> impl Iterator<Item = &[u8]> {
+        self.lines().map(str::as_bytes)
+    }
+
+    /// Groups of lines separated by blank lines, without the separators.
+    /// Use [`str::lines`] to walk the lines of a record.
+    pub fn records(&self) -> impl Iterator<Item = &str> {
+        let buffer = self.buffer.as_str();
+        let mut offset = 0;
+
+        std::iter::from_fn(move || {
+            let mut start = None;
+            let mut end = offset;
+
+            for line in buffer[offset..].split_inclusive('\n') {
+                let blank = line.trim_end_matches(['\r', '\n']).is_empty();
+                match (blank, start) {
+                    (true, Some(_)) => break,
+                    (false, None) => start = Some(end),
+                    _ => {},
+                }
+                end += line.len();
+            }
+
+            offset = end;
+            start.map(|start| buffer[start..end].trim_end_matches(['\r', '\n']))
+        })
+    }
+}
+
+impl From<String> for Input {
+    fn from(buffer: String) -> Self {
+        Input::new(buffer)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(buffer: &str) -> Self {
+        Input::new(buffer.to_string())
+    }
+}
+
+/// Every line gets a `\n` ending, so `lines()` gives back the same lines.
+impl From<Vec<String>> for Input {
+    fn from(lines: Vec<String>) -> Self {
+        lines.iter().map(String::as_str).collect()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Input {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(lines: T) -> Self {
+        let mut buffer = String::new();
+        for line in lines {
+            buffer.push_str(line);
+            buffer.push('\n');
+        }
+
+        Input::new(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Input;
+
+    #[test]
+    fn lines() {
+        let input = Input::from("a\r\nbb\n\nccc");
+
+        assert_eq!(input.lines().collect::<Vec<&str>>(), vec!["a", "bb", "", "ccc"]);
+        assert_eq!(input.byte_lines().map(|line| line.len()).collect::<Vec<usize>>(), vec![1, 2, 0, 3]);
+        assert_eq!(Input::default().lines().count(), 0);
+    }
+
+    #[test]
+    fn records() {
+        let input = Input::from("\n\na\nb\n\n\nc\r\n\r\nd\n\n");
+        assert_eq!(input.records().collect::<Vec<&str>>(), vec!["a\nb", "c", "d"]);
+
+        let input = Input::from("a\nb");
+        assert_eq!(input.records().collect::<Vec<&str>>(), vec!["a\nb"]);
+        assert_eq!(Input::from("\n\n").records().count(), 0);
+    }
+
+    #[test]
+    fn from_lines() {
+        let lines = vec!["a".to_string(), "".to_string(), "b".to_string(), "".to_string()];
+        let input = Input::from(lines.clone());
+
+        assert_eq!(input.as_str(), "a\n\nb\n\n");
+        assert_eq!(input.lines().map(String::from).collect::<Vec<String>>(), lines);
+        assert_eq!(["x", "y"].into_iter().collect::<Input>(), Input::from("x\ny\n"));
+    }
+
+    #[test]
+    fn bytes() {
+        let input = Input::from("abc");
+
+        assert_eq!(input.as_bytes(), b"abc");
+        assert_eq!(input.len(), 3);
+        assert!(!input.is_empty());
+    }
+}
@@ -0,0 +1,196 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::input::Input;
+
+use super::{Error, Filesystem, InMemoryFilesystem};
+
+/// Files of a `.zip`, `.tar`, `.tar.gz` or `.tgz` archive, read once when
+/// it is opened. Paths are the paths inside the archive, so an archive of
+/// the `input` directory has `input/day01`. Entries that aren't UTF-8 text,
+/// like a picture next to the inputs, are left out.
+#[derive(Debug, Clone)]
+pub struct ArchiveFilesystem {
+    files: InMemoryFilesystem,
+}
+
+impl ArchiveFilesystem {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let name = path.display().to_string();
+        let error = |_| Error::ReadError(name.clone());
+        let file = File::open(path).map_err(error)?;
+
+        let files = if name.ends_with(".zip") {
+            read_zip(file)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            read_tar(flate2::read::GzDecoder::new(file))
+        } else if name.ends_with(".tar") {
+            read_tar(file)
+        } else {
+            return Err(Error::ReadError(format!("{}, not a .zip, .tar, .tar.gz or .tgz archive", name)));
+        };
+
+        files.map(|files| ArchiveFilesystem { files })
+            .map_err(|err| Error::ReadError(format!("{}, {}", name, err)))
+    }
+
+    /// Paths of every file in the archive, sorted.
+    pub fn paths(&self) -> Vec<&str> {
+        self.files.paths()
+    }
+}
+
+impl Filesystem for ArchiveFilesystem {
+    fn path_exists(&self, path: &str) -> bool {
+        self.files.path_exists(path)
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        self.files.read_file(path)
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        self.files.sub_dirs(path)
+    }
+}
+
+fn read_zip(file: File) -> Result<InMemoryFilesystem, String> {
+    let mut archive = zip::ZipArchive::new(file).map_err(|err| err.to_string())?;
+    let mut files = InMemoryFilesystem::new();
+
+    for idx in 0..archive.len() {
+        let mut entry = archive.by_index(idx).map_err(|err| err.to_string())?;
+        if entry.is_dir() {
+            continue;
+        }
+
+        let name = entry.name().trim_start_matches("./").to_string();
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content).map_err(|err| format!("{}: {}", name, err))?;
+        insert_text(&mut files, &name, content);
+    }
+
+    Ok(files)
+}
+
+fn read_tar(reader: impl Read) -> Result<InMemoryFilesystem, String> {
+    let mut archive = tar::Archive::new(reader);
+    let mut files = InMemoryFilesystem::new();
+
+    for entry in archive.entries().map_err(|err| err.to_string())? {
+        let mut entry = entry.map_err(|err| err.to_string())?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let name = entry.path().map_err(|err| err.to_string())?.display().to_string();
+        let name = name.trim_start_matches("./").to_string();
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content).map_err(|err| format!("{}: {}", name, err))?;
+        insert_text(&mut files, &name, content);
+    }
+
+    Ok(files)
+}
+
+fn insert_text(files: &mut InMemoryFilesystem, name: &str, content: Vec<u8>) {
+    if let Ok(content) = String::from_utf8(content) {
+        files.insert(name, content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use crate::io::{Error, Filesystem};
+
+    use super::ArchiveFilesystem;
+
+    const FILES: [(&str, &str); 2] = [("input/day01", "1000\n2000\n"), ("input/day02", "A Y\n")];
+    /// Not text, left out of the files.
+    const PICTURE: (&str, &[u8]) = ("input/day01.png", &[0x89, b'P', b'N', b'G', 0xff, 0xfe]);
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-archive-{}-{}", std::process::id(), name))
+    }
+
+    fn check(fs: &ArchiveFilesystem) {
+        assert_eq!(fs.paths(), vec!["input/day01", "input/day02"]);
+        assert!(fs.path_exists("input/day01"));
+        assert!(!fs.path_exists("input"));
+        assert_eq!(fs.read_file("input/day02").unwrap().as_str(), "A Y\n");
+        assert_eq!(fs.read_file("input/day03"), Err(Error::ReadError("input/day03".to_string())));
+    }
+
+    fn write_tar(writer: impl Write) {
+        let mut builder = tar::Builder::new(writer);
+        for (name, content) in FILES {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, content.as_bytes()).unwrap();
+        }
+        let mut header = tar::Header::new_gnu();
+        header.set_size(PICTURE.1.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, PICTURE.0, PICTURE.1).unwrap();
+        builder.into_inner().unwrap();
+    }
+
+    #[test]
+    fn zip() {
+        let path = temp_path("inputs.zip");
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        writer.add_directory("input/", zip::write::SimpleFileOptions::default()).unwrap();
+        for (name, content) in FILES {
+            writer.start_file(name, zip::write::SimpleFileOptions::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.start_file(PICTURE.0, zip::write::SimpleFileOptions::default()).unwrap();
+        writer.write_all(PICTURE.1).unwrap();
+        writer.finish().unwrap();
+
+        check(&ArchiveFilesystem::open(&path).unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn tar() {
+        let path = temp_path("inputs.tar");
+        write_tar(std::fs::File::create(&path).unwrap());
+
+        check(&ArchiveFilesystem::open(&path).unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn tar_gz() {
+        let path = temp_path("inputs.tgz");
+        let file = std::fs::File::create(&path).unwrap();
+        write_tar(flate2::write::GzEncoder::new(file, flate2::Compression::default()));
+
+        check(&ArchiveFilesystem::open(&path).unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn errors() {
+        let err = ArchiveFilesystem::open("inputs.rar").unwrap_err();
+        assert_eq!(format!("{}", err), "unable to read file: inputs.rar");
+
+        let err = ArchiveFilesystem::open("tests/fixtures/valid_file").unwrap_err();
+        assert_eq!(format!("{}", err), "unable to read file: tests/fixtures/valid_file, not a .zip, .tar, .tar.gz or .tgz archive");
+
+        let path = temp_path("damaged.zip");
+        std::fs::write(&path, "something\n").unwrap();
+        let err = ArchiveFilesystem::open(&path).unwrap_err();
+        assert!(format!("{}", err).starts_with(&format!("unable to read file: {}, ", path.display())), "{}", err);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+use crate::input::Input;
+
+use super::{Error, Filesystem, LocalFilesystem};
+
+/// Local directory seen at another path, for example the example inputs
+/// served as `input/`:
+///
+/// ```
+/// use aoc::io::{DirFilesystem, Filesystem};
+///
+/// let fs = DirFilesystem::new("tests/fixtures").at("input");
+///
+/// assert!(fs.path_exists("input/valid_file"));
+/// assert!(!fs.path_exists("tests/fixtures/valid_file"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirFilesystem {
+    root: PathBuf,
+    mount: String,
+}
+
+impl DirFilesystem {
+    /// Paths are relative to `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        DirFilesystem { root: root.into(), mount: String::new() }
+    }
+
+    /// Only paths under `mount` exist, `mount/day01` is `root/day01`.
+    pub fn at(mut self, mount: &str) -> Self {
+        self.mount = mount.trim_end_matches('/').to_string();
+        self
+    }
+
+    fn resolve(&self, path: &str) -> Option<String> {
+        let relative = match self.mount.as_str() {
+            "" => path,
+            mount => match path.strip_prefix(mount)? {
+                "" => "",
+                relative => relative.strip_prefix('/')?,
+            },
+        };
+
+        Some(self.root.join(Path::new(relative)).display().to_string())
+    }
+}
+
+impl Filesystem for DirFilesystem {
+    fn path_exists(&self, path: &str) -> bool {
+        self.resolve(path).is_some_and(|path| LocalFilesystem{}.path_exists(&path))
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        match self.resolve(path) {
+            Some(resolved) => LocalFilesystem{}.read_file(&resolved).map_err(|_| Error::ReadError(path.to_string())),
+            None => Err(Error::ReadError(path.to_string())),
+        }
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        self.resolve(path).map(|path| LocalFilesystem{}.sub_dirs(&path)).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Error, Filesystem};
+
+    use super::DirFilesystem;
+
+    #[test]
+    fn root() {
+        let fs = DirFilesystem::new("tests");
+
+        assert!(fs.path_exists("fixtures/valid_file"));
+        assert_eq!(fs.read_file("fixtures/valid_file").unwrap().as_str(), "something\n");
+    }
+
+    #[test]
+    fn mounted() {
+        let fs = DirFilesystem::new("tests/fixtures").at("input/");
+
+        assert!(fs.path_exists("input/valid_file"));
+        assert!(!fs.path_exists("inputs/valid_file"));
+        assert!(!fs.path_exists("valid_file"));
+        assert_eq!(fs.read_file("input/valid_file").unwrap().as_str(), "something\n");
+        // Errors name the path that was asked for.
+        assert_eq!(fs.read_file("input/day01"), Err(Error::ReadError("input/day01".to_string())));
+    }
+
+    #[test]
+    fn sub_dirs() {
+        let fs = DirFilesystem::new("tests").at("input");
+
+        assert_eq!(fs.sub_dirs("input"), vec!["fixtures"]);
+        assert!(fs.sub_dirs("inputs").is_empty());
+    }
+}
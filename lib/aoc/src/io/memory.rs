@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::input::Input;
+
+use super::{Error, Filesystem};
+
+/// Files kept in memory, for tests and for library users that already have
+/// their inputs.
+///
+/// ```
+/// use aoc::io::{Filesystem, InMemoryFilesystem};
+///
+/// let fs = InMemoryFilesystem::new()
+///     .with_file("input/day01", "1000\n2000\n");
+///
+/// assert!(fs.path_exists("input/day01"));
+/// assert_eq!(fs.read_file("input/day01").unwrap().as_str(), "1000\n2000\n");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryFilesystem {
+    files: HashMap<String, Input>,
+}
+
+impl InMemoryFilesystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_file(mut self, path: &str, content: impl Into<Input>) -> Self {
+        self.insert(path, content);
+        self
+    }
+
+    pub fn insert(&mut self, path: &str, content: impl Into<Input>) {
+        self.files.insert(path.to_string(), content.into());
+    }
+
+    pub fn remove(&mut self, path: &str) {
+        self.files.remove(path);
+    }
+
+    /// Paths of every file, sorted.
+    pub fn paths(&self) -> Vec<&str> {
+        let mut paths = self.files.keys().map(String::as_str).collect::<Vec<&str>>();
+        paths.sort();
+
+        paths
+    }
+}
+
+impl Filesystem for InMemoryFilesystem {
+    fn path_exists(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        match self.files.get(path) {
+            None => Err(Error::ReadError(path.to_string())),
+            Some(content) => Ok(content.clone()),
+        }
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        super::sub_dirs(self.files.keys().map(String::as_str), Path::new(path))
+    }
+}
+
+impl<P: Into<String>, C: Into<Input>> FromIterator<(P, C)> for InMemoryFilesystem {
+    fn from_iter<T: IntoIterator<Item = (P, C)>>(files: T) -> Self {
+        InMemoryFilesystem {
+            files: files.into_iter().map(|(path, content)| (path.into(), content.into())).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::Input;
+    use crate::io::{Error, Filesystem};
+
+    use super::InMemoryFilesystem;
+
+    #[test]
+    fn files() {
+        let mut fs = InMemoryFilesystem::new()
+            .with_file("input/day01", "1\n")
+            .with_file("input/day02", vec!["a".to_string(), "b".to_string()]);
+
+        assert!(fs.path_exists("input/day01"));
+        assert!(!fs.path_exists("input"));
+        assert_eq!(fs.read_file("input/day02"), Ok(Input::from("a\nb\n")));
+        assert_eq!(fs.paths(), vec!["input/day01", "input/day02"]);
+        assert!(fs.sub_dirs("input").is_empty());
+
+        fs.insert("input/alice/day01", "2\n");
+        assert_eq!(fs.sub_dirs("input"), vec!["alice"]);
+        assert_eq!(fs.sub_dirs(""), vec!["input"]);
+        fs.remove("input/alice/day01");
+
+        fs.remove("input/day01");
+        assert!(!fs.path_exists("input/day01"));
+        assert_eq!(fs.read_file("input/day01"), Err(Error::ReadError("input/day01".to_string())));
+    }
+
+    #[test]
+    fn from_iter() {
+        let fs = [("a", "1"), ("b", "2")].into_iter().collect::<InMemoryFilesystem>();
+
+        assert_eq!(fs.read_file("b"), Ok(Input::from("2")));
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::input::Input;
+
+mod archive;
+mod dir;
+mod memory;
+mod overlay;
+
+pub use archive::ArchiveFilesystem;
+pub use dir::DirFilesystem;
+pub use memory::InMemoryFilesystem;
+pub use overlay::OverlayFilesystem;
+
+#[derive(Debug,PartialEq,Eq)]
+pub enum Error {
+    ReadError(String),
+    /// The file is encrypted and can't be decrypted.
+    Encrypted(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReadError(file) => write!(f, "unable to read file: {}", file),
+            Self::Encrypted(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Where inputs are read from. Every path `read_file` can read must exist
+/// for `path_exists`, the runner checks it before reading.
+pub trait Filesystem {
+    fn path_exists(&self, path: &str) -> bool;
+    fn read_file(&self, path: &str) -> Result<Input, Error>;
+    /// Names of the directories right under `path`, sorted. Nothing when
+    /// `path` isn't a directory.
+    fn sub_dirs(&self, path: &str) -> Vec<String>;
+}
+
+/// Names of the directories right under `dir` that hold some of the files,
+/// sorted, for filesystems that only know the paths of their files.
+pub fn sub_dirs<'a>(files: impl IntoIterator<Item = &'a str>, dir: &Path) -> Vec<String> {
+    files.into_iter()
+        .filter_map(|file| Path::new(file).strip_prefix(dir).ok())
+        .filter(|relative| relative.components().count() > 1)
+        .filter_map(|relative| relative.iter().next()?.to_str().map(str::to_string))
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect()
+}
+
+impl<F: Filesystem + ?Sized> Filesystem for Box<F> {
+    fn path_exists(&self, path: &str) -> bool {
+        (**self).path_exists(path)
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        (**self).read_file(path)
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        (**self).sub_dirs(path)
+    }
+}
+
+pub struct LocalFilesystem {}
+
+impl Filesystem for LocalFilesystem {
+    fn path_exists(&self, path: &str) -> bool {
+        std::path::Path::new(path).exists()
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(Input::from(content)),
+            Err(_) => Err(Error::ReadError(path.to_string())),
+        }
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        let mut dirs = std::fs::read_dir(path)
+            .map(|entries| {
+                entries.filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+        dirs.sort();
+
+        dirs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Filesystem, LocalFilesystem, Error};
+
+    #[test]
+    fn no_error() {
+        let fs = LocalFilesystem{};
+
+        let filename = "tests/fixtures/valid_file";
+        match fs.read_file(filename) {
+            Ok(input) => {
+                assert_eq!(input.lines().collect::<Vec<&str>>(), vec!["something"])
+            },
+            Err(err) => {
+                panic!("{}", err);
+            },
+        }
+    }
+
+    #[test]
+    fn file_not_found() {
+        let fs = LocalFilesystem{};
+
+        let filename = "__file_is_not_here__";
+        match fs.read_file(filename) {
+            Ok(_) => {
+                panic!("file should not exist: {}", filename);
+            },
+            Err(err) => {
+                assert_eq!(err, Error::ReadError(filename.to_string()));
+                assert_eq!(format!("{}", err), format!("unable to read file: {}", filename))
+            },
+        }
+    }
+
+    #[test]
+    fn sub_dirs() {
+        let dir = std::env::temp_dir().join(format!("aoc-io-dirs-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("bob")).unwrap();
+        std::fs::create_dir_all(dir.join("alice")).unwrap();
+        std::fs::write(dir.join("day01"), "1").unwrap();
+
+        assert_eq!(LocalFilesystem{}.sub_dirs(&dir.display().to_string()), vec!["alice", "bob"]);
+        assert!(LocalFilesystem{}.sub_dirs(&dir.join("day01").display().to_string()).is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+
+        let files = ["input/day01", "input/bob/day01", "input/alice/x/day01", "other/carol/day01"];
+        assert_eq!(super::sub_dirs(files, Path::new("input")), vec!["alice", "bob"]);
+        assert_eq!(super::sub_dirs(files, Path::new("input/")), vec!["alice", "bob"]);
+        assert!(super::sub_dirs(files, Path::new("input/day01")).is_empty());
+    }
+
+    #[test]
+    fn invalid_line() {
+        // A bad line fails the whole read, the input isn't silently cut short.
+        let path = std::env::temp_dir().join(format!("aoc-io-invalid-{}", std::process::id()));
+        std::fs::write(&path, b"1\n\xff\n2\n").unwrap();
+        let filename = path.display().to_string();
+
+        assert_eq!(LocalFilesystem{}.read_file(&filename), Err(Error::ReadError(filename.clone())));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeSet;
+
+use crate::input::Input;
+
+use super::{Error, Filesystem};
+
+/// Several filesystems seen as one, a file is read from the first layer
+/// that has it. For example the real inputs with the example inputs as a
+/// fallback:
+///
+/// ```
+/// use aoc::io::{DirFilesystem, Filesystem, InMemoryFilesystem, OverlayFilesystem};
+///
+/// let fs = OverlayFilesystem::new()
+///     .with_layer(InMemoryFilesystem::new().with_file("input/day01", "real\n"))
+///     .with_layer(DirFilesystem::new("tests/fixtures").at("input"));
+///
+/// assert_eq!(fs.read_file("input/day01").unwrap().as_str(), "real\n");
+/// assert_eq!(fs.read_file("input/valid_file").unwrap().as_str(), "something\n");
+/// ```
+#[derive(Default)]
+pub struct OverlayFilesystem {
+    layers: Vec<Box<dyn Filesystem>>,
+}
+
+impl OverlayFilesystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer below the existing ones.
+    pub fn with_layer(mut self, fs: impl Filesystem + 'static) -> Self {
+        self.layers.push(Box::new(fs));
+        self
+    }
+}
+
+impl Filesystem for OverlayFilesystem {
+    fn path_exists(&self, path: &str) -> bool {
+        self.layers.iter().any(|fs| fs.path_exists(path))
+    }
+
+    fn read_file(&self, path: &str) -> Result<Input, Error> {
+        match self.layers.iter().find(|fs| fs.path_exists(path)) {
+            Some(fs) => fs.read_file(path),
+            None => Err(Error::ReadError(path.to_string())),
+        }
+    }
+
+    fn sub_dirs(&self, path: &str) -> Vec<String> {
+        self.layers.iter()
+            .flat_map(|fs| fs.sub_dirs(path))
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Error, Filesystem, InMemoryFilesystem};
+
+    use super::OverlayFilesystem;
+
+    #[test]
+    fn layers() {
+        let fs = OverlayFilesystem::new()
+            .with_layer(InMemoryFilesystem::new().with_file("day01", "top\n"))
+            .with_layer(InMemoryFilesystem::new().with_file("day01", "bottom\n").with_file("day02", "bottom\n"));
+
+        assert!(fs.path_exists("day01"));
+        assert!(fs.path_exists("day02"));
+        assert!(!fs.path_exists("day03"));
+        assert_eq!(fs.read_file("day01").unwrap().as_str(), "top\n");
+        assert_eq!(fs.read_file("day02").unwrap().as_str(), "bottom\n");
+        assert_eq!(fs.read_file("day03"), Err(Error::ReadError("day03".to_string())));
+    }
+
+    #[test]
+    fn sub_dirs() {
+        let fs = OverlayFilesystem::new()
+            .with_layer(InMemoryFilesystem::new().with_file("bob/day01", "").with_file("alice/day01", ""))
+            .with_layer(InMemoryFilesystem::new().with_file("bob/day02", "").with_file("carol/day01", ""));
+
+        assert_eq!(fs.sub_dirs(""), vec!["alice", "bob", "carol"]);
+    }
+
+    #[test]
+    fn empty() {
+        let fs = OverlayFilesystem::new();
+
+        assert!(!fs.path_exists("day01"));
+        assert_eq!(fs.read_file("day01"), Err(Error::ReadError("day01".to_string())));
+    }
+}
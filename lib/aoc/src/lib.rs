@@ -2,16 +2,67 @@ extern crate core;
 
 pub mod args;
 pub mod io;
+pub mod input;
 pub mod range;
 pub mod range_pair;
 pub mod math;
 pub mod grid_helper;
+pub mod external;
+pub mod parse;
+pub mod plugin;
+pub mod run;
+pub mod cancel;
+pub mod progress;
+pub mod cache;
+pub mod puzzle;
+pub mod config;
+pub mod encrypted;
+pub mod metadata;
+pub mod registry;
 
-pub trait Solver {
+use args::part::Part;
+use input::Input;
+use metadata::Metadata;
+
+pub trait Solver: Send {
     fn name(&self) -> &'static str;
-    fn read_lines(&mut self, lines: Vec<String>);
+    fn read_input(&mut self, input: &Input);
     fn part1(&mut self) -> String;
     fn part2(&mut self) -> String;
+
+    /// Set a parameter of the puzzle from the configuration, like the row
+    /// to check on day 15. It's called before `read_input`.
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter: {}", name))
+    }
+
+    /// False for the placeholder of the days without a solution, see
+    /// [`MissingSolution`].
+    fn is_implemented(&self) -> bool {
+        true
+    }
+
+    /// What tells the answers of this solver apart from the other solvers
+    /// with the same name, for the answer cache. Solvers outside of the
+    /// binary return what identifies their build.
+    fn variant(&self) -> Option<String> {
+        None
+    }
+
+    /// Title, URL, tags and parameters of the puzzle, empty by default.
+    fn metadata(&self) -> Metadata {
+        Metadata::default()
+    }
+
+    /// Solve the given part. Solvers that can fail without panicking (for
+    /// example when they call something outside of the process) can
+    /// override it to report the error.
+    fn solve(&mut self, part: &Part) -> Result<String, String> {
+        match part {
+            Part::Part1 => Ok(self.part1()),
+            Part::Part2 => Ok(self.part2()),
+        }
+    }
 }
 #[derive(Default)]
 pub struct MissingSolution {}
@@ -21,7 +72,11 @@ impl Solver for MissingSolution {
         "MissingSolution"
     }
 
-    fn read_lines(&mut self, _: Vec<String>) {
+    fn is_implemented(&self) -> bool {
+        false
+    }
+
+    fn read_input(&mut self, _: &Input) {
         todo!()
     }
 
@@ -43,13 +98,19 @@ impl MissingSolution {
 #[cfg(test)]
 mod tests {
     use crate::Solver;
+    use crate::input::Input;
 
     use super::MissingSolution;
 
+    #[test]
+    fn is_implemented() {
+        assert!(!MissingSolution::new().is_implemented());
+    }
+
     #[test]
     #[should_panic]
-    fn read_lines() {
-        MissingSolution::new().read_lines(Vec::new());
+    fn read_input() {
+        MissingSolution::new().read_input(&Input::default());
     }
 
     #[test]
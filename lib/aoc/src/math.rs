@@ -34,7 +34,7 @@ impl<T: PrimInt + std::fmt::Debug> Vec2D<T> {
                 (self.manhattan_to(v), v.clone())
             })
             .collect::<Vec<(T, Self)>>();
-        others.sort_by(|(d1, _), (d2, _)| d1.cmp(d2));
+        others.sort_by_key(|(d, _)| *d);
         others.remove(0).1
     }
 }
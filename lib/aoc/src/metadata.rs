@@ -0,0 +1,44 @@
+//! What a solver is about, for listings and help.
+//!
+//! ```
+//! use aoc::metadata::{Metadata, Param};
+//!
+//! let metadata = Metadata {
+//!     title: "Beacon Exclusion Zone",
+//!     url: "https://adventofcode.com/2022/day/15",
+//!     tags: &["geometry", "ranges"],
+//!     params: &[Param { name: "target_line", description: "Row checked by part 1", default: "2000000" }],
+//!     examples: 1,
+//!     ..Default::default()
+//! };
+//!
+//! assert_eq!(metadata.param("target_line").map(|param| param.default), Some("2000000"));
+//! ```
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    /// Title of the puzzle, without the day.
+    pub title: &'static str,
+    pub url: &'static str,
+    /// One or two sentences, the question of part 1.
+    pub description: &'static str,
+    /// Techniques used, like "pathfinding" or "simulation".
+    pub tags: &'static [&'static str],
+    /// Parameters accepted by `Solver::set_param`.
+    pub params: &'static [Param],
+    /// Number of example inputs in the puzzle.
+    pub examples: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default: &'static str,
+}
+
+impl Metadata {
+    pub fn param(&self, name: &str) -> Option<&Param> {
+        self.params.iter().find(|param| param.name == name)
+    }
+}
@@ -0,0 +1,82 @@
+/// All (optionally negative) integers in a line, in order. A `-` right
+/// after a digit separates two numbers, like in a range.
+///
+/// ```
+/// let values = aoc::parse::integers("Sensor at x=2, y=-18: closest beacon is at x=-2, y=15");
+///
+/// assert_eq!(values, vec![2, -18, -2, 15]);
+/// assert_eq!(aoc::parse::integers("2-4,6-8"), vec![2, 4, 6, 8]);
+/// ```
+pub fn integers(line: &str) -> Vec<i64> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut previous = None;
+
+    for ch in line.chars() {
+        let after_digit = previous.is_some_and(|previous: char| previous.is_ascii_digit());
+        previous = Some(ch);
+        if ch.is_ascii_digit() || (ch == '-' && current.is_empty() && !after_digit) {
+            current.push(ch);
+            continue
+        }
+
+        if let Ok(value) = current.parse::<i64>() {
+            values.push(value);
+        }
+
+        current.clear();
+        if ch == '-' && !after_digit {
+            current.push(ch);
+        }
+    }
+
+    if let Ok(value) = current.parse::<i64>() {
+        values.push(value);
+    }
+
+    values
+}
+
+/// Split lines into blank-line separated blocks. Empty blocks are dropped,
+/// so a missing or an extra trailing blank line makes no difference.
+///
+/// ```
+/// let lines: Vec<String> = vec!["1", "2", "", "3", ""].iter().map(|s| s.to_string()).collect();
+///
+/// assert_eq!(aoc::parse::blocks(&lines), vec![vec!["1", "2"], vec!["3"]]);
+/// ```
+pub fn blocks(lines: &[String]) -> Vec<Vec<String>> {
+    lines.split(|line| line.is_empty())
+        .filter(|block| !block.is_empty())
+        .map(|block| block.to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn integers() {
+        let cases: Vec<(&str, Vec<i64>)> = vec![
+            ("", vec![]),
+            ("noop", vec![]),
+            ("addx -13", vec![-13]),
+            ("move 12 from 3 to 9", vec![12, 3, 9]),
+            ("2-4,6-8", vec![2, 4, 6, 8]),
+            ("from -3-5", vec![-3, 5]),
+            ("x=-2--3", vec![-2, -3]),
+            ("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB", vec![0]),
+        ];
+
+        for case in cases {
+            assert_eq!(super::integers(case.0), case.1, "integers({:?})", case.0);
+        }
+    }
+
+    #[test]
+    fn blocks() {
+        let lines: Vec<String> = ["", "1", "2", "", "", "3"].iter().map(|s| s.to_string()).collect();
+
+        assert_eq!(super::blocks(&lines), vec![vec!["1", "2"], vec!["3"]]);
+        assert!(super::blocks(&[]).is_empty());
+    }
+}
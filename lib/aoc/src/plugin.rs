@@ -0,0 +1,511 @@
+//! Solvers loaded from `cdylib` plugins.
+//!
+//! A plugin exports one symbol, [`REGISTER_SYMBOL`], a function without
+//! arguments that returns a pointer to a static [`PluginDeclaration`]. The
+//! first field of the declaration is always the ABI version, the host checks
+//! it before it looks at anything else, so the rest of the layout can change
+//! with the version.
+//!
+//! The easiest way to write a plugin is [`declare_plugin!`](crate::declare_plugin):
+//!
+//! ```ignore
+//! aoc::declare_plugin!("my-solutions", 17 => my_solutions::day17::Solution::new);
+//! ```
+use std::ffi::{CStr, CString};
+use std::fmt::Display;
+use std::os::raw::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use libloading::Library;
+
+use crate::{cache, Solver};
+use crate::args::{day::Day, part::Part};
+use crate::input::Input;
+
+/// Version of the plugin ABI, bump it on any change in [`PluginDeclaration`]
+/// or in the meaning of the status codes.
+pub const ABI_VERSION: u32 = 2;
+/// Name of the registration function a plugin has to export.
+pub const REGISTER_SYMBOL: &str = "aoc_plugin_register";
+
+pub const STATUS_OK: i32 = 0;
+pub const STATUS_UNKNOWN_DAY: i32 = 1;
+pub const STATUS_UNKNOWN_PART: i32 = 2;
+pub const STATUS_INVALID_INPUT: i32 = 3;
+pub const STATUS_PANIC: i32 = 4;
+/// The solver returned an error, see [`Solver::solve`].
+pub const STATUS_ERROR: i32 = 5;
+
+pub type RegisterFn = unsafe extern "C" fn() -> *const PluginDeclaration;
+/// Solve `part` of `day` with the input in `input[..len]` (UTF-8, lines
+/// separated with `\n`). On [`STATUS_OK`] the answer, otherwise the error
+/// message, is stored in `answer` and has to be released with `free`.
+pub type SolveFn = unsafe extern "C" fn(day: u8, part: u8, input: *const u8, len: usize, answer: *mut *mut c_char) -> i32;
+pub type FreeFn = unsafe extern "C" fn(answer: *mut c_char);
+
+#[repr(C)]
+pub struct PluginDeclaration {
+    pub abi_version: u32,
+    /// NUL terminated plugin name.
+    pub name: *const c_char,
+    pub days: *const u8,
+    pub day_count: usize,
+    pub solve: SolveFn,
+    pub free: FreeFn,
+}
+
+// Only ever points to static data in the plugin.
+unsafe impl Sync for PluginDeclaration {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    LoadError(String, String),
+    MissingSymbol(String),
+    VersionMismatch(String, u32),
+    InvalidDeclaration(String, String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LoadError(path, err) => write!(f, "unable to load plugin {}: {}", path, err),
+            Self::MissingSymbol(path) => write!(f, "{} is not a plugin: missing symbol {}", path, REGISTER_SYMBOL),
+            Self::VersionMismatch(path, version) => write!(
+                f, "plugin {} was built for ABI version {}, this runner supports version {}; rebuild the plugin",
+                path, version, ABI_VERSION,
+            ),
+            Self::InvalidDeclaration(path, reason) => write!(f, "invalid plugin {}: {}", path, reason),
+        }
+    }
+}
+
+struct Handle {
+    solve: SolveFn,
+    free: FreeFn,
+    // Keep the library loaded as long as anyone can call the functions above.
+    _library: Option<Library>,
+}
+
+/// A loaded plugin.
+pub struct Plugin {
+    path: String,
+    name: String,
+    /// Hash of the library file, it changes with every rebuild.
+    build: u64,
+    days: Vec<Day>,
+    handle: Arc<Handle>,
+}
+
+impl Plugin {
+    /// Load the plugin at `path` and check its ABI version.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let display = path.display().to_string();
+
+        // Loading a library runs its initializers, we trust what is in the
+        // plugin directory.
+        let library = unsafe { Library::new(path) }
+            .map_err(|err| Error::LoadError(display.clone(), err.to_string()))?;
+
+        let declaration = unsafe {
+            let register = library.get::<RegisterFn>(REGISTER_SYMBOL.as_bytes())
+                .map_err(|_| Error::MissingSymbol(display.clone()))?;
+            register()
+        };
+
+        unsafe { Self::from_declaration(&display, declaration, Some(library)) }
+    }
+
+    /// # Safety
+    ///
+    /// `declaration` has to be null or point to a declaration that starts
+    /// with the ABI version. If the version matches, it has to be valid for
+    /// as long as the plugin is in use.
+    unsafe fn from_declaration(path: &str, declaration: *const PluginDeclaration, library: Option<Library>) -> Result<Self, Error> {
+        if declaration.is_null() {
+            return Err(Error::InvalidDeclaration(path.to_string(), "null declaration".into()));
+        }
+
+        // Don't trust anything but the version before we know the layout.
+        let version = *(declaration as *const u32);
+        if version != ABI_VERSION {
+            return Err(Error::VersionMismatch(path.to_string(), version));
+        }
+
+        let declaration = &*declaration;
+
+        if declaration.name.is_null() || (declaration.days.is_null() && declaration.day_count > 0) {
+            return Err(Error::InvalidDeclaration(path.to_string(), "null pointer".into()));
+        }
+
+        let name = CStr::from_ptr(declaration.name).to_string_lossy().to_string();
+        let days = if declaration.day_count == 0 {
+            Vec::new()
+        } else {
+            std::slice::from_raw_parts(declaration.days, declaration.day_count).to_vec()
+        };
+        let days = days.iter()
+            .map(|day| Day::from_str(&format!("{}", day)))
+            .collect::<Result<Vec<Day>, String>>()
+            .map_err(|err| Error::InvalidDeclaration(path.to_string(), err))?;
+
+        Ok(Self {
+            path: path.to_string(),
+            name,
+            build: std::fs::read(path).map(|bytes| cache::hash(&bytes)).unwrap_or_default(),
+            days,
+            handle: Arc::new(Handle {
+                solve: declaration.solve,
+                free: declaration.free,
+                _library: library,
+            }),
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn days(&self) -> &[Day] {
+        &self.days
+    }
+
+    /// Solver for `day`, if the plugin has one.
+    pub fn solver(&self, day: &Day) -> Option<PluginSolver> {
+        if !self.days.contains(day) {
+            return None;
+        }
+
+        Some(PluginSolver {
+            day: day.clone(),
+            variant: format!("{}:{}:{:016x}", self.path, self.name, self.build),
+            handle: self.handle.clone(),
+            input: String::new(),
+        })
+    }
+}
+
+/// Load every plugin (files with the platform's dynamic library extension)
+/// in `dir`. A missing directory means no plugins. Plugins that can't be
+/// loaded are reported separately, they don't stop the rest from loading.
+pub fn discover(dir: &Path) -> (Vec<Plugin>, Vec<Error>) {
+    let mut plugins = Vec::new();
+    let mut errors = Vec::new();
+
+    let mut paths = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| is_library(path))
+            .collect::<Vec<PathBuf>>(),
+        Err(_) => return (plugins, errors),
+    };
+    paths.sort();
+
+    for path in paths {
+        match Plugin::load(&path) {
+            Ok(plugin) => plugins.push(plugin),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    (plugins, errors)
+}
+
+fn is_library(path: &Path) -> bool {
+    let suffix = std::env::consts::DLL_SUFFIX.trim_start_matches('.');
+
+    path.is_file() && path.extension().map(|ext| ext == suffix).unwrap_or(false)
+}
+
+/// Solver for a single day of a [`Plugin`].
+pub struct PluginSolver {
+    day: Day,
+    variant: String,
+    handle: Arc<Handle>,
+    input: String,
+}
+
+impl Solver for PluginSolver {
+    fn name(&self) -> &'static str {
+        "PluginSolver"
+    }
+
+    fn variant(&self) -> Option<String> {
+        Some(self.variant.clone())
+    }
+
+    fn read_input(&mut self, input: &Input) {
+        self.input = input.as_str().to_string();
+    }
+
+    fn part1(&mut self) -> String {
+        match self.solve(&Part::Part1) {
+            Ok(answer) => answer,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn part2(&mut self) -> String {
+        match self.solve(&Part::Part2) {
+            Ok(answer) => answer,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn solve(&mut self, part: &Part) -> Result<String, String> {
+        let day = format!("{}", self.day).parse::<u8>().unwrap();
+        let part_number = format!("{}", part).parse::<u8>().unwrap();
+        let mut answer: *mut c_char = std::ptr::null_mut();
+
+        let status = unsafe {
+            (self.handle.solve)(day, part_number, self.input.as_ptr(), self.input.len(), &mut answer)
+        };
+
+        let message = if answer.is_null() {
+            String::new()
+        } else {
+            let message = unsafe { CStr::from_ptr(answer) }.to_string_lossy().to_string();
+            unsafe { (self.handle.free)(answer) };
+            message
+        };
+
+        match status {
+            STATUS_OK => Ok(message),
+            STATUS_UNKNOWN_DAY => Err(format!("plugin has no solution for day {}", self.day)),
+            STATUS_UNKNOWN_PART => Err(format!("plugin has no solution for part {}", part)),
+            STATUS_INVALID_INPUT => Err(format!("plugin rejected the input: {}", message)),
+            STATUS_PANIC => Err(format!("plugin panicked: {}", message)),
+            STATUS_ERROR => Err(message),
+            _ => Err(format!("plugin returned unknown status {}: {}", status, message)),
+        }
+    }
+}
+
+/// Plugin side of [`SolveFn`]: run `solver` on the raw input and store the
+/// answer (or the error message) in `answer`. Errors of the solver are
+/// reported as [`STATUS_ERROR`]. Panics don't cross the ABI, they are
+/// reported as [`STATUS_PANIC`].
+///
+/// # Safety
+///
+/// `input` has to point to `len` readable bytes and `answer` has to be a
+/// valid pointer to write to.
+pub unsafe fn solve(mut solver: Box<dyn Solver>, part: u8, input: *const u8, len: usize, answer: *mut *mut c_char) -> i32 {
+    let bytes = if len == 0 { &[] } else { std::slice::from_raw_parts(input, len) };
+
+    let input = match std::str::from_utf8(bytes) {
+        Ok(input) => input,
+        Err(err) => return respond(answer, STATUS_INVALID_INPUT, err.to_string()),
+    };
+    let part = match Part::from_str(&format!("{}", part)) {
+        Ok(part) => part,
+        Err(err) => return respond(answer, STATUS_UNKNOWN_PART, err),
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        solver.read_input(&Input::from(input));
+        solver.solve(&part)
+    }));
+
+    match result {
+        Ok(Ok(value)) => respond(answer, STATUS_OK, value),
+        Ok(Err(err)) => respond(answer, STATUS_ERROR, err),
+        Err(panic) => {
+            let message = panic.downcast_ref::<String>().cloned()
+                .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_else(|| "unknown panic".to_string());
+            respond(answer, STATUS_PANIC, message)
+        },
+    }
+}
+
+/// Plugin side of [`FreeFn`].
+///
+/// # Safety
+///
+/// `answer` has to come from [`solve`] and must not be used afterwards.
+pub unsafe fn free(answer: *mut c_char) {
+    if !answer.is_null() {
+        drop(CString::from_raw(answer));
+    }
+}
+
+unsafe fn respond(answer: *mut *mut c_char, status: i32, message: String) -> i32 {
+    let message = CString::new(message.replace('\0', "")).unwrap();
+    *answer = message.into_raw();
+    status
+}
+
+/// Export the solvers of a crate as a plugin. Build the crate with
+/// `crate-type = ["cdylib"]` and copy the library into the plugin directory.
+///
+/// ```ignore
+/// aoc::declare_plugin!(
+///     "experiments",
+///     17 => experiments::day17::Solution::new,
+///     18 => experiments::day18::Solution::new,
+/// );
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($name:literal, $($day:literal => $constructor:path),+ $(,)?) => {
+        static AOC_PLUGIN_DAYS: &[u8] = &[$($day),+];
+
+        unsafe extern "C" fn aoc_plugin_solve(
+            day: u8, part: u8, input: *const u8, len: usize, answer: *mut *mut ::std::os::raw::c_char,
+        ) -> i32 {
+            let solver: Box<dyn $crate::Solver> = match day {
+                $($day => Box::new($constructor()),)+
+                _ => return $crate::plugin::STATUS_UNKNOWN_DAY,
+            };
+
+            $crate::plugin::solve(solver, part, input, len, answer)
+        }
+
+        unsafe extern "C" fn aoc_plugin_free(answer: *mut ::std::os::raw::c_char) {
+            $crate::plugin::free(answer)
+        }
+
+        static AOC_PLUGIN_DECLARATION: $crate::plugin::PluginDeclaration = $crate::plugin::PluginDeclaration {
+            abi_version: $crate::plugin::ABI_VERSION,
+            name: concat!($name, "\0").as_ptr() as *const ::std::os::raw::c_char,
+            days: AOC_PLUGIN_DAYS.as_ptr(),
+            day_count: AOC_PLUGIN_DAYS.len(),
+            solve: aoc_plugin_solve,
+            free: aoc_plugin_free,
+        };
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_register() -> *const $crate::plugin::PluginDeclaration {
+            &AOC_PLUGIN_DECLARATION
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::Solver;
+    use crate::args::{day::Day, part::Part};
+    use crate::input::Input;
+
+    use super::{Error, Plugin, PluginDeclaration};
+
+    // The macro is meant for cdylib crates, but it works just as well to
+    // build an in-process declaration to test the host side.
+    mod fake {
+        #[derive(Default)]
+        pub struct Lines { lines: usize }
+
+        impl crate::Solver for Lines {
+            fn name(&self) -> &'static str { "fake::Lines" }
+            fn read_input(&mut self, input: &crate::input::Input) { self.lines = input.lines().count(); }
+            fn part1(&mut self) -> String { format!("{}", self.lines) }
+            fn part2(&mut self) -> String { panic!("no part 2") }
+        }
+
+        impl Lines {
+            pub fn new() -> Self { Self::default() }
+        }
+
+        #[derive(Default)]
+        pub struct Failing {}
+
+        impl crate::Solver for Failing {
+            fn name(&self) -> &'static str { "fake::Failing" }
+            fn read_input(&mut self, _: &crate::input::Input) {}
+            fn part1(&mut self) -> String { unreachable!() }
+            fn part2(&mut self) -> String { unreachable!() }
+            fn solve(&mut self, _: &crate::args::part::Part) -> Result<String, String> { Err("no answer".to_string()) }
+        }
+
+        impl Failing {
+            pub fn new() -> Self { Self::default() }
+        }
+
+        crate::declare_plugin!("fake", 17 => Lines::new, 18 => Lines::new, 19 => Failing::new);
+
+        pub fn declaration() -> *const crate::plugin::PluginDeclaration {
+            aoc_plugin_register()
+        }
+    }
+
+    fn fake() -> Plugin {
+        unsafe { Plugin::from_declaration("fake.so", fake::declaration(), None) }.unwrap()
+    }
+
+    #[test]
+    fn declaration() {
+        let plugin = fake();
+
+        assert_eq!(plugin.name(), "fake");
+        assert_eq!(plugin.path(), "fake.so");
+        assert_eq!(plugin.days(), &[Day::Day17, Day::Day18, Day::Day19]);
+        assert!(plugin.solver(&Day::Day01).is_none());
+        assert_eq!(plugin.solver(&Day::Day17).unwrap().variant(), Some("fake.so:fake:0000000000000000".to_string()));
+    }
+
+    #[test]
+    fn solve() {
+        let mut solver = fake().solver(&Day::Day17).unwrap();
+        solver.read_input(&Input::from("a\nb\nc\n"));
+
+        assert_eq!(solver.name(), "PluginSolver");
+        assert_eq!(solver.solve(&Part::Part1), Ok("3".to_string()));
+        assert_eq!(solver.part1(), "3".to_string());
+        assert_eq!(solver.solve(&Part::Part2), Err("plugin panicked: no part 2".to_string()));
+    }
+
+    #[test]
+    fn solve_error() {
+        let mut solver = fake().solver(&Day::Day19).unwrap();
+        solver.read_input(&Input::from("a\n"));
+
+        assert_eq!(solver.solve(&Part::Part1), Err("no answer".to_string()));
+    }
+
+    #[test]
+    fn version_mismatch() {
+        let mut declaration = unsafe { std::ptr::read(fake::declaration()) };
+        declaration.abi_version = 99;
+
+        let plugin = unsafe { Plugin::from_declaration("old.so", &declaration as *const PluginDeclaration, None) };
+        let err = plugin.err().unwrap();
+
+        assert_eq!(err, Error::VersionMismatch("old.so".into(), 99));
+        assert_eq!(
+            format!("{}", err),
+            "plugin old.so was built for ABI version 99, this runner supports version 2; rebuild the plugin",
+        );
+    }
+
+    #[test]
+    fn null_declaration() {
+        let plugin = unsafe { Plugin::from_declaration("null.so", std::ptr::null(), None) };
+
+        assert_eq!(plugin.err().unwrap(), Error::InvalidDeclaration("null.so".into(), "null declaration".into()));
+    }
+
+    #[test]
+    fn not_a_library() {
+        let err = Plugin::load(Path::new("tests/fixtures/valid_file")).err().unwrap();
+
+        assert!(matches!(err, Error::LoadError(_, _)));
+    }
+
+    #[test]
+    fn discover_missing_dir() {
+        let (plugins, errors) = super::discover(Path::new("__dir_is_not_here__"));
+
+        assert!(plugins.is_empty());
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! Progress reporting for long running solvers.
+//!
+//! Solvers declare what they are doing with [`start`] and call [`tick`] as
+//! they go. Both are no-ops unless the runner installed a [`Progress`] on the
+//! thread, so they are cheap to leave in.
+//!
+//! ```
+//! use aoc::progress::{self, Progress};
+//!
+//! let progress = Progress::new();
+//! progress::install(progress.clone());
+//!
+//! progress::start("rounds", 10_000);
+//! for _ in 0..1_234 {
+//!     progress::tick();
+//! }
+//!
+//! assert_eq!(format!("{}", progress.snapshot()), "rounds: 1234/10000 (12%)");
+//! ```
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const BAR_WIDTH: usize = 30;
+
+#[derive(Debug, Default)]
+struct Shared {
+    label: Mutex<String>,
+    total: AtomicU64,
+    current: AtomicU64,
+}
+
+/// Progress of a solver, clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct Progress {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct State {
+    pub label: String,
+    /// Zero if the solver doesn't know how much work is left.
+    pub total: u64,
+    pub current: u64,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.total {
+            0 => write!(f, "{}: {}", self.label, self.current),
+            total => write!(f, "{}: {}/{} ({}%)", self.label, self.current, total, self.current * 100 / total),
+        }
+    }
+}
+
+impl State {
+    /// `[#####.....] rounds: 5/10 (50%)`, without the bar if there is no total.
+    pub fn bar(&self) -> String {
+        if self.total == 0 {
+            return format!("{}", self);
+        }
+
+        let filled = (self.current.min(self.total) * BAR_WIDTH as u64 / self.total) as usize;
+
+        format!("[{}{}] {}", "#".repeat(filled), ".".repeat(BAR_WIDTH - filled), self)
+    }
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new step, `total` is zero if it's unknown.
+    pub fn start(&self, label: &str, total: u64) {
+        *self.shared.label.lock().unwrap() = label.to_string();
+        self.shared.total.store(total, Ordering::Relaxed);
+        self.shared.current.store(0, Ordering::Relaxed);
+    }
+
+    pub fn advance(&self, amount: u64) {
+        self.shared.current.fetch_add(amount, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> State {
+        State {
+            label: self.shared.label.lock().unwrap().clone(),
+            total: self.shared.total.load(Ordering::Relaxed),
+            current: self.shared.current.load(Ordering::Relaxed),
+        }
+    }
+
+    /// True once a solver started a step.
+    pub fn is_started(&self) -> bool {
+        !self.shared.label.lock().unwrap().is_empty()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Progress>> = const { RefCell::new(None) };
+}
+
+/// Report [`start`] and [`tick`] calls of the current thread to `progress`.
+pub fn install(progress: Progress) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(progress));
+}
+
+/// Stop reporting on the current thread.
+pub fn uninstall() {
+    CURRENT.with(|current| *current.borrow_mut() = None);
+}
+
+/// Progress installed on the current thread, if any.
+pub fn current() -> Option<Progress> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+pub fn start(label: &str, total: u64) {
+    CURRENT.with(|current| {
+        if let Some(progress) = current.borrow().as_ref() {
+            progress.start(label, total);
+        }
+    });
+}
+
+pub fn tick() {
+    CURRENT.with(|current| {
+        if let Some(progress) = current.borrow().as_ref() {
+            progress.advance(1);
+        }
+    });
+}
+
+/// Draws the progress on stderr until it's dropped.
+pub struct Renderer {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Renderer {
+    pub fn spawn(progress: Progress) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        let handle = thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                if progress.is_started() {
+                    eprint!("\r\x1b[2K{}", progress.snapshot().bar());
+                    let _ = std::io::stderr().flush();
+                }
+
+                thread::sleep(Duration::from_millis(100));
+            }
+
+            if progress.is_started() {
+                eprint!("\r\x1b[2K");
+            }
+        });
+
+        Renderer { stop, handle: Some(handle) }
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{install, start, tick, uninstall, Progress, State};
+
+    #[test]
+    fn no_progress() {
+        start("nothing", 10);
+        tick();
+    }
+
+    #[test]
+    fn report() {
+        let progress = Progress::new();
+        assert!(!progress.is_started());
+        install(progress.clone());
+
+        start("rows", 4);
+        tick();
+        assert!(progress.is_started());
+        assert_eq!(progress.snapshot(), State { label: "rows".into(), total: 4, current: 1 });
+
+        start("columns", 0);
+        tick();
+        tick();
+        assert_eq!(progress.snapshot(), State { label: "columns".into(), total: 0, current: 2 });
+
+        uninstall();
+        tick();
+        assert_eq!(progress.snapshot().current, 2);
+    }
+
+    #[test]
+    fn bar() {
+        let state = State { label: "rounds".into(), total: 10, current: 5 };
+        assert_eq!(state.bar(), format!("[{}{}] rounds: 5/10 (50%)", "#".repeat(15), ".".repeat(15)));
+
+        let state = State { label: "sand".into(), total: 0, current: 42 };
+        assert_eq!(state.bar(), "sand: 42");
+    }
+}
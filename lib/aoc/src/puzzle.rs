@@ -0,0 +1,170 @@
+//! Solutions split in a parse step and two pure parts.
+//!
+//! A [`Puzzle`] parses the input once into its own type, both parts only
+//! read it. [`Adapter`] turns it into a [`Solver`] for the runner, the time
+//! spent in `read_input` is then the parsing time.
+//!
+//! ```
+//! use aoc::Solver;
+//! use aoc::input::Input;
+//! use aoc::puzzle::{Adapter, Puzzle};
+//!
+//! struct Sum;
+//!
+//! impl Puzzle for Sum {
+//!     const NAME: &'static str = "Sum";
+//!     type Input = Vec<i64>;
+//!
+//!     fn parse(input: &str) -> Result<Self::Input, String> {
+//!         input.lines().map(|line| line.parse().map_err(|_| format!("not a number: {}", line))).collect()
+//!     }
+//!
+//!     fn part1(input: &Self::Input) -> String {
+//!         format!("{}", input.iter().sum::<i64>())
+//!     }
+//!
+//!     fn part2(input: &Self::Input) -> String {
+//!         format!("{}", input.len())
+//!     }
+//! }
+//!
+//! assert_eq!(Sum::part1(&Sum::parse("1\n2\n").unwrap()), "3");
+//!
+//! let mut solver = Adapter::<Sum>::new();
+//! solver.read_input(&Input::from("1\n2\n3\n"));
+//! assert_eq!(solver.part1(), "6");
+//! assert_eq!(solver.part2(), "3");
+//! ```
+use std::marker::PhantomData;
+
+use crate::Solver;
+use crate::args::part::Part;
+use crate::input::Input;
+use crate::metadata::Metadata;
+
+pub trait Puzzle {
+    /// Reported as the solver name.
+    const NAME: &'static str;
+    type Input: Send;
+
+    fn parse(input: &str) -> Result<Self::Input, String>;
+    fn part1(input: &Self::Input) -> String;
+    fn part2(input: &Self::Input) -> String;
+
+    fn metadata() -> Metadata {
+        Metadata::default()
+    }
+}
+
+/// [`Solver`] of any [`Puzzle`], both parts share the same parsed input.
+pub struct Adapter<P: Puzzle> {
+    input: Option<Result<P::Input, String>>,
+    // `fn() -> P` so the adapter is `Send` whatever `P` is.
+    puzzle: PhantomData<fn() -> P>,
+}
+
+impl<P: Puzzle> Adapter<P> {
+    pub fn new() -> Self {
+        Adapter { input: None, puzzle: PhantomData }
+    }
+}
+
+impl<P: Puzzle> Default for Adapter<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Puzzle> Solver for Adapter<P> {
+    fn name(&self) -> &'static str {
+        P::NAME
+    }
+
+    fn read_input(&mut self, input: &Input) {
+        self.input = Some(P::parse(input.as_str()));
+    }
+
+    fn metadata(&self) -> Metadata {
+        P::metadata()
+    }
+
+    fn part1(&mut self) -> String {
+        self.solve(&Part::Part1).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn part2(&mut self) -> String {
+        self.solve(&Part::Part2).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn solve(&mut self, part: &Part) -> Result<String, String> {
+        let input = match &self.input {
+            None => return Err("no input".to_string()),
+            Some(Err(err)) => return Err(format!("invalid input: {}", err)),
+            Some(Ok(input)) => input,
+        };
+
+        match part {
+            Part::Part1 => Ok(P::part1(input)),
+            Part::Part2 => Ok(P::part2(input)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solver;
+    use crate::args::part::Part;
+    use crate::input::Input;
+
+    use super::{Adapter, Puzzle};
+
+    struct Words;
+
+    impl Puzzle for Words {
+        const NAME: &'static str = "Words";
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Input, String> {
+            match input.is_empty() {
+                true => Err("empty".to_string()),
+                false => Ok(input.split_whitespace().map(String::from).collect()),
+            }
+        }
+
+        fn part1(input: &Self::Input) -> String {
+            format!("{}", input.len())
+        }
+
+        fn part2(input: &Self::Input) -> String {
+            input.join("-")
+        }
+    }
+
+    #[test]
+    fn adapter() {
+        let mut solver = Adapter::<Words>::new();
+        solver.read_input(&Input::from("a b\nc\n"));
+
+        assert_eq!(solver.name(), "Words");
+        assert_eq!(solver.solve(&Part::Part1), Ok("3".to_string()));
+        assert_eq!(solver.solve(&Part::Part2), Ok("a-b-c".to_string()));
+        assert_eq!(solver.part1(), "3");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let mut solver = Adapter::<Words>::new();
+        assert_eq!(solver.solve(&Part::Part1), Err("no input".to_string()));
+
+        solver.read_input(&Input::default());
+        assert_eq!(solver.solve(&Part::Part2), Err("invalid input: empty".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid input: empty")]
+    fn invalid_input_part1() {
+        let mut solver = Adapter::<Words>::new();
+        solver.read_input(&Input::default());
+        solver.part1();
+    }
+}
@@ -72,10 +72,10 @@ mod tests {
             ("6-6", Ok(Range::new(6, 6))),
             ("2-8", Ok(Range::new(2, 8))),
             ("8-2", Ok(Range::new(2, 8))),
-            ("a-8", Err(format!("invalid range: a-8"))),
-            ("8-c", Err(format!("invalid range: 8-c"))),
-            ("1-3-4", Err(format!("invalid range: 1-3-4"))),
-            ("123", Err(format!("invalid range: 123"))),
+            ("a-8", Err("invalid range: a-8".to_string())),
+            ("8-c", Err("invalid range: 8-c".to_string())),
+            ("1-3-4", Err("invalid range: 1-3-4".to_string())),
+            ("123", Err("invalid range: 123".to_string())),
         ];
 
         for case in cases {
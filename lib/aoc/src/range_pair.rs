@@ -2,7 +2,7 @@ use std::str::FromStr;
 use crate::range::Range;
 use num::PrimInt;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct RangePair<T>(Range<T>, Range<T>);
 
 impl<T: PartialOrd + FromStr> FromStr for RangePair<T> {
@@ -67,11 +67,11 @@ mod tests {
             ("2-3,4-5", Ok(RangePair(Range::new(2, 3), Range::new(4, 5)))),
             ("6-6,4-6", Ok(RangePair(Range::new(6, 6), Range::new(4, 6)))),
             ("8-2,2-8", Ok(RangePair(Range::new(2, 8), Range::new(2, 8)))),
-            ("a-8,1-3", Err(format!("invalid pair: invalid range: a-8"))),
-            ("8-c,1-3", Err(format!("invalid pair: invalid range: 8-c"))),
-            ("2-4", Err(format!("invalid pair: 2-4"))),
-            ("1-3-4", Err(format!("invalid pair: 1-3-4"))),
-            ("123", Err(format!("invalid pair: 123"))),
+            ("a-8,1-3", Err("invalid pair: invalid range: a-8".to_string())),
+            ("8-c,1-3", Err("invalid pair: invalid range: 8-c".to_string())),
+            ("2-4", Err("invalid pair: 2-4".to_string())),
+            ("1-3-4", Err("invalid pair: 1-3-4".to_string())),
+            ("123", Err("invalid pair: 123".to_string())),
         ];
 
         for case in cases {
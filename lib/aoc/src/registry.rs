@@ -0,0 +1,133 @@
+//! Solvers of every year, each year comes from its own solution crate.
+//!
+//! ```
+//! use aoc::args::{day::Day, year::Year};
+//! use aoc::registry::Registry;
+//! use aoc::{MissingSolution, Solver};
+//!
+//! fn solvers_2023(_: &Day) -> Box<dyn Solver> {
+//!     Box::new(MissingSolution::new())
+//! }
+//!
+//! let registry = Registry::new().with_year(Year::new(2023), solvers_2023);
+//!
+//! assert_eq!(registry.years(), vec![Year::new(2023)]);
+//! assert!(registry.days(&Year::new(2023)).is_empty());
+//! assert!(registry.solvers(&Year::new(2024)).is_err());
+//! ```
+use crate::args::{day::Day, year::Year};
+use crate::{MissingSolution, Solver};
+
+/// Solver of a day, like `solution::get_solver`.
+pub type Solvers = fn(&Day) -> Box<dyn Solver>;
+
+#[derive(Default, Clone)]
+pub struct Registry {
+    years: Vec<(Year, Solvers)>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the solvers of a year, replacing the ones already there.
+    pub fn with_year(mut self, year: Year, solvers: Solvers) -> Self {
+        self.years.retain(|(known, _)| *known != year);
+        self.years.push((year, solvers));
+        self.years.sort_by_key(|(year, _)| *year);
+        self
+    }
+
+    /// Years with solvers, in order.
+    pub fn years(&self) -> Vec<Year> {
+        self.years.iter().map(|(year, _)| *year).collect()
+    }
+
+    pub fn solvers(&self, year: &Year) -> Result<Solvers, String> {
+        self.years.iter()
+            .find(|(known, _)| known == year)
+            .map(|(_, solvers)| *solvers)
+            .ok_or_else(|| format!("no solutions for {}", year))
+    }
+
+    /// Solver of the day, [`MissingSolution`] for unknown years and days.
+    pub fn solver(&self, year: &Year, day: &Day) -> Box<dyn Solver> {
+        match self.solvers(year) {
+            Ok(solvers) => solvers(day),
+            Err(_) => Box::new(MissingSolution::new()),
+        }
+    }
+
+    /// Days of the year with a solver, in order.
+    pub fn days(&self, year: &Year) -> Vec<Day> {
+        Day::all().into_iter()
+            .filter(|day| self.solver(year, day).is_implemented())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::args::{day::Day, year::Year};
+    use crate::input::Input;
+    use crate::{MissingSolution, Solver};
+
+    use super::Registry;
+
+    struct Answer(&'static str);
+
+    impl Solver for Answer {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn read_input(&mut self, _: &Input) {}
+
+        fn part1(&mut self) -> String {
+            self.0.to_string()
+        }
+
+        fn part2(&mut self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    fn solvers_2022(day: &Day) -> Box<dyn Solver> {
+        match day {
+            Day::Day01 => Box::new(Answer("2022")),
+            _ => Box::new(MissingSolution::new()),
+        }
+    }
+
+    fn solvers_2023(day: &Day) -> Box<dyn Solver> {
+        match day {
+            Day::Day01 | Day::Day02 => Box::new(Answer("2023")),
+            _ => Box::new(MissingSolution::new()),
+        }
+    }
+
+    #[test]
+    fn years() {
+        let registry = Registry::new()
+            .with_year(Year::new(2023), solvers_2023)
+            .with_year(Year::new(2022), solvers_2022);
+
+        assert_eq!(registry.years(), vec![Year::new(2022), Year::new(2023)]);
+        assert_eq!(registry.solver(&Year::new(2022), &Day::Day01).part1(), "2022");
+        assert_eq!(registry.solver(&Year::new(2023), &Day::Day01).part1(), "2023");
+        assert_eq!(registry.days(&Year::new(2023)), vec![Day::Day01, Day::Day02]);
+        assert!(!registry.solver(&Year::new(2024), &Day::Day01).is_implemented());
+        assert_eq!(registry.solvers(&Year::new(2024)).err(), Some("no solutions for 2024".to_string()));
+    }
+
+    #[test]
+    fn replace() {
+        let registry = Registry::new()
+            .with_year(Year::new(2022), solvers_2023)
+            .with_year(Year::new(2022), solvers_2022);
+
+        assert_eq!(registry.years(), vec![Year::new(2022)]);
+        assert_eq!(registry.days(&Year::new(2022)), vec![Day::Day01]);
+    }
+}
@@ -0,0 +1,524 @@
+//! Run a solver on its input without going through the command line.
+//!
+//! ```
+//! use aoc::args::{day::Day, part::Part};
+//! use aoc::io::LocalFilesystem;
+//! use aoc::run::{RunOptions, Runner};
+//!
+//! let fs = LocalFilesystem{};
+//! let runner = Runner::new(&fs);
+//!
+//! // No solvers are registered, every day is missing.
+//! let options = RunOptions { input: Some("tests/fixtures/valid_file".into()), ..Default::default() };
+//! let err = runner.solve(&Day::Day01, &Part::Part1, options).unwrap_err();
+//! assert_eq!(format!("{}", err), "day 01 is not implemented");
+//! ```
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::args::{day::Day, part::Part};
+use crate::cache::{Cache, CacheMode};
+use crate::cancel::{self, Token};
+use crate::input::Input;
+use crate::io::{self, Filesystem};
+use crate::progress::{self, Progress, Renderer};
+use crate::{MissingSolution, Solver};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    Input(io::Error),
+    NotImplemented(Day),
+    Solver(String),
+    Panic(String),
+    Timeout(Duration),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input(err) => write!(f, "{}", err),
+            Self::NotImplemented(day) => write!(f, "day {} is not implemented", day),
+            Self::Solver(err) => write!(f, "{}", err),
+            Self::Panic(message) => write!(f, "solver panicked: {}", message),
+            Self::Timeout(timeout) => write!(f, "solver did not finish in {:?}", timeout),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RunOptions {
+    /// Read the input from this path instead of `input/dayNN`.
+    pub input: Option<String>,
+    /// Use this solver instead of the registered one.
+    pub solver: Option<Box<dyn Solver>>,
+    /// Run the solver on a worker thread and give up after this long.
+    pub timeout: Option<Duration>,
+    /// Collect the progress of the solver, drawn on stderr if it's a terminal.
+    pub progress: Option<Progress>,
+    /// How to use the cache of the runner, if it has one.
+    pub cache: CacheMode,
+    /// Tells apart solvers with the same name in the cache, for example the
+    /// command of an external solver.
+    pub variant: Option<String>,
+    /// Parameters given to the solver with `Solver::set_param`.
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Timings {
+    /// Time spent in `read_input`.
+    pub bootstrap: Duration,
+    /// Time spent solving the part.
+    pub solution: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RunReport {
+    pub day: Day,
+    pub part: Part,
+    pub solver: &'static str,
+    pub input: String,
+    pub answer: String,
+    pub timings: Timings,
+    /// The answer came from the cache, the timings are zero.
+    pub cached: bool,
+}
+
+pub struct Runner<'a, F: Filesystem> {
+    fs: &'a F,
+    input_dir: String,
+    solvers: fn(&Day) -> Box<dyn Solver>,
+    cache: Option<Cache>,
+}
+
+impl<'a, F: Filesystem> Runner<'a, F> {
+    pub fn new(fs: &'a F) -> Self {
+        Runner {
+            fs,
+            input_dir: "input".to_string(),
+            solvers: |_| Box::new(MissingSolution::new()),
+            cache: None,
+        }
+    }
+
+    /// Directory of the `dayNN` inputs, `input` by default.
+    pub fn with_input_dir(mut self, dir: &str) -> Self {
+        self.input_dir = dir.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Where to find the solver of a day, usually `solution::get_solver`.
+    pub fn with_solvers(mut self, solvers: fn(&Day) -> Box<dyn Solver>) -> Self {
+        self.solvers = solvers;
+        self
+    }
+
+    /// `input/dayNN-partP` if it exists, `input/dayNN` otherwise.
+    pub fn input_filename(&self, day: &Day, part: &Part) -> String {
+        let file_path_without_part = format!("{}/day{:0>2}", self.input_dir, day);
+        let file_path_with_part = format!("{}/day{:0>2}-part{}", self.input_dir, day, part);
+
+        if self.fs.path_exists(&file_path_with_part) {
+            file_path_with_part
+        } else {
+            file_path_without_part
+        }
+    }
+
+    pub fn solve(&self, day: &Day, part: &Part, options: RunOptions) -> Result<RunReport, Error> {
+        let filename = match options.input {
+            Some(name) => name,
+            None => self.input_filename(day, part),
+        };
+
+        let input = self.fs.read_file(&filename).map_err(Error::Input)?;
+
+        let mut solver = match options.solver {
+            Some(solver) => solver,
+            None => (self.solvers)(day),
+        };
+        if !solver.is_implemented() {
+            return Err(Error::NotImplemented(day.clone()));
+        }
+
+        for (param, value) in &options.params {
+            solver.set_param(param, value)
+                .map_err(|err| Error::Solver(format!("day {}: {}", day, err)))?;
+        }
+
+        let name = solver.name();
+        let mut variant = name.to_string();
+        for extra in solver.variant().iter().chain(&options.variant) {
+            variant.push_str(&format!(":{}", extra));
+        }
+        for (param, value) in &options.params {
+            variant.push_str(&format!(":{}={}", param, value));
+        }
+        let cache = self.cache.as_ref()
+            .filter(|_| options.cache != CacheMode::Disabled)
+            .map(|cache| (cache, cache.key(day, part, &variant, &input)));
+
+        if let Some((cache, key)) = &cache {
+            if let Some(answer) = cache.get(key).filter(|_| options.cache == CacheMode::Use) {
+                return Ok(RunReport {
+                    day: day.clone(),
+                    part: part.clone(),
+                    solver: name,
+                    input: filename,
+                    answer,
+                    timings: Timings::default(),
+                    cached: true,
+                });
+            }
+        }
+
+        let renderer = match &options.progress {
+            Some(progress) => {
+                progress::install(progress.clone());
+                std::io::stderr().is_terminal().then(|| Renderer::spawn(progress.clone()))
+            },
+            None => None,
+        };
+
+        let result = match options.timeout {
+            Some(timeout) => execute_timeout(solver, part, input, timeout),
+            None => execute(solver.as_mut(), part, &input),
+        };
+
+        if options.progress.is_some() {
+            progress::uninstall();
+        }
+        drop(renderer);
+
+        let (answer, timings) = result?;
+
+        if let Some((cache, key)) = &cache {
+            // The cache is only an optimisation, failing to write it is fine.
+            let _ = cache.put(key, &answer);
+        }
+
+        Ok(RunReport {
+            day: day.clone(),
+            part: part.clone(),
+            solver: name,
+            input: filename,
+            answer,
+            timings,
+            cached: false,
+        })
+    }
+}
+
+/// Feed the input to the solver and solve the part, timing both steps.
+pub fn execute(solver: &mut dyn Solver, part: &Part, input: &Input) -> Result<(String, Timings), Error> {
+    let start_time = Instant::now();
+    solver.read_input(input);
+    let bootstrap = start_time.elapsed();
+
+    let start_time = Instant::now();
+    let answer = solver.solve(part).map_err(Error::Solver)?;
+
+    Ok((answer, Timings { bootstrap, solution: start_time.elapsed() }))
+}
+
+/// Same as [`execute`] but on a worker thread, panics are reported as errors.
+/// After `timeout` the solver's [`cancel`] token is cancelled and the worker
+/// is left to stop on its own. The [`progress`] of the calling thread is
+/// carried over to the worker.
+pub fn execute_timeout(
+    mut solver: Box<dyn Solver>,
+    part: &Part,
+    input: Input,
+    timeout: Duration,
+) -> Result<(String, Timings), Error> {
+    let token = Token::new();
+    let worker_token = token.clone();
+    let part = part.clone();
+    let progress = progress::current();
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        cancel::install(worker_token);
+        if let Some(progress) = progress {
+            progress::install(progress);
+        }
+        let result = catch_unwind(AssertUnwindSafe(|| execute(solver.as_mut(), &part, &input)));
+        let _ = sender.send(result);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(result)) => result,
+        Ok(Err(panic)) => {
+            let message = panic.downcast_ref::<String>().cloned()
+                .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(Error::Panic(message))
+        },
+        Err(_) => {
+            token.cancel();
+            Err(Error::Timeout(timeout))
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use crate::args::{day::Day, part::Part};
+    use crate::cache::{Cache, CacheMode};
+    use crate::cancel;
+    use crate::input::Input;
+    use crate::io::{self, InMemoryFilesystem};
+    use crate::progress::{self, Progress, State};
+    use crate::{MissingSolution, Solver};
+
+    use super::{Error, RunOptions, Runner};
+
+    fn filesystem() -> InMemoryFilesystem {
+        InMemoryFilesystem::new()
+            .with_file("input/day01", "a\nbb\n")
+            .with_file("input/day02-part1", "")
+            .with_file("input/day02-part2", "")
+    }
+
+    #[derive(Default)]
+    struct Lengths {
+        lines: Vec<usize>,
+        offset: usize,
+    }
+
+    impl Solver for Lengths {
+        fn name(&self) -> &'static str {
+            "Lengths"
+        }
+
+        fn read_input(&mut self, input: &Input) {
+            self.lines = input.lines().map(str::len).collect();
+        }
+
+        fn part1(&mut self) -> String {
+            progress::start("lines", self.lines.len() as u64);
+            self.lines.iter().for_each(|_| progress::tick());
+
+            self.lines.len().to_string()
+        }
+
+        fn part2(&mut self) -> String {
+            (self.lines.iter().sum::<usize>() + self.offset).to_string()
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+            match name {
+                "offset" => self.offset = value.parse().map_err(|_| format!("invalid offset: {}", value))?,
+                _ => return Err(format!("unknown parameter: {}", name)),
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Part 1 spins until it's cancelled, part 2 panics.
+    #[derive(Default)]
+    struct Spin {
+        stopped: Arc<AtomicBool>,
+    }
+
+    impl Solver for Spin {
+        fn name(&self) -> &'static str {
+            "Spin"
+        }
+
+        fn read_input(&mut self, _: &Input) {}
+
+        fn part1(&mut self) -> String {
+            while !cancel::is_cancelled() {
+                std::thread::yield_now();
+            }
+            self.stopped.store(true, Ordering::Relaxed);
+
+            String::new()
+        }
+
+        fn part2(&mut self) -> String {
+            panic!("nope")
+        }
+    }
+
+    fn solvers(day: &Day) -> Box<dyn Solver> {
+        match day {
+            Day::Day01 | Day::Day02 => Box::<Lengths>::default(),
+            _ => Box::new(MissingSolution::new()),
+        }
+    }
+
+    #[test]
+    fn input_filename() {
+        let fs = filesystem();
+        let runner = Runner::new(&fs);
+
+        // No part1 and part2 files
+        assert_eq!(runner.input_filename(&Day::Day01, &Part::Part1), "input/day01".to_string());
+        assert_eq!(runner.input_filename(&Day::Day01, &Part::Part2), "input/day01".to_string());
+        // Has separate part1 and part2 files
+        assert_eq!(runner.input_filename(&Day::Day02, &Part::Part1), "input/day02-part1".to_string());
+        assert_eq!(runner.input_filename(&Day::Day02, &Part::Part2), "input/day02-part2".to_string());
+
+        let runner = Runner::new(&fs).with_input_dir("other/");
+        assert_eq!(runner.input_filename(&Day::Day01, &Part::Part1), "other/day01".to_string());
+    }
+
+    #[test]
+    fn solve() {
+        let fs = filesystem();
+        let runner = Runner::new(&fs).with_solvers(solvers);
+
+        let report = runner.solve(&Day::Day01, &Part::Part2, RunOptions::default()).unwrap();
+        assert_eq!(report.day, Day::Day01);
+        assert_eq!(report.part, Part::Part2);
+        assert_eq!(report.solver, "Lengths");
+        assert_eq!(report.input, "input/day01");
+        assert_eq!(report.answer, "3");
+
+        let report = runner.solve(&Day::Day02, &Part::Part1, RunOptions::default()).unwrap();
+        assert_eq!(report.input, "input/day02-part1");
+        assert_eq!(report.answer, "0");
+    }
+
+    #[test]
+    fn solve_with_options() {
+        let mut fs = filesystem();
+        fs.insert("custom", vec!["x".to_string(); 5]);
+        let runner = Runner::new(&fs);
+
+        let options = RunOptions {
+            input: Some("custom".into()),
+            solver: Some(Box::<Lengths>::default()),
+            ..Default::default()
+        };
+        let report = runner.solve(&Day::Day10, &Part::Part1, options).unwrap();
+        assert_eq!(report.input, "custom");
+        assert_eq!(report.answer, "5");
+    }
+
+    #[test]
+    fn params() {
+        let fs = filesystem();
+        let runner = Runner::new(&fs).with_solvers(solvers);
+
+        let params = |params: &[(&str, &str)]| RunOptions {
+            params: params.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+            ..Default::default()
+        };
+
+        let report = runner.solve(&Day::Day01, &Part::Part2, params(&[("offset", "10")])).unwrap();
+        assert_eq!(report.answer, "13");
+
+        let err = runner.solve(&Day::Day01, &Part::Part2, params(&[("speed", "1")])).unwrap_err();
+        assert_eq!(format!("{}", err), "day 01: unknown parameter: speed");
+    }
+
+    #[test]
+    fn errors() {
+        let fs = filesystem();
+        let runner = Runner::new(&fs).with_solvers(solvers);
+
+        let err = runner.solve(&Day::Day03, &Part::Part1, RunOptions::default()).unwrap_err();
+        assert_eq!(err, Error::Input(io::Error::ReadError("input/day03".into())));
+        assert_eq!(format!("{}", err), "unable to read file: input/day03");
+
+        let options = RunOptions { input: Some("input/day01".into()), ..Default::default() };
+        let err = runner.solve(&Day::Day03, &Part::Part1, options).unwrap_err();
+        assert_eq!(err, Error::NotImplemented(Day::Day03));
+        assert_eq!(format!("{}", err), "day 03 is not implemented");
+    }
+
+    #[test]
+    fn timeout() {
+        let fs = filesystem();
+        let runner = Runner::new(&fs).with_solvers(solvers);
+
+        let options = RunOptions { timeout: Some(Duration::from_secs(10)), ..Default::default() };
+        let report = runner.solve(&Day::Day01, &Part::Part2, options).unwrap();
+        assert_eq!(report.answer, "3");
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let options = RunOptions {
+            solver: Some(Box::new(Spin { stopped: stopped.clone() })),
+            timeout: Some(Duration::from_millis(10)),
+            ..Default::default()
+        };
+        let err = runner.solve(&Day::Day01, &Part::Part1, options).unwrap_err();
+        assert_eq!(err, Error::Timeout(Duration::from_millis(10)));
+        assert_eq!(format!("{}", err), "solver did not finish in 10ms");
+
+        // The worker notices the cancellation and stops.
+        let start = Instant::now();
+        while !stopped.load(Ordering::Relaxed) {
+            assert!(start.elapsed() < Duration::from_secs(10), "worker is still running");
+            std::thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn panic() {
+        let fs = filesystem();
+        let runner = Runner::new(&fs);
+
+        let options = RunOptions {
+            solver: Some(Box::<Spin>::default()),
+            timeout: Some(Duration::from_secs(10)),
+            ..Default::default()
+        };
+        let err = runner.solve(&Day::Day01, &Part::Part2, options).unwrap_err();
+        assert_eq!(format!("{}", err), "solver panicked: nope");
+    }
+
+    #[test]
+    fn progress() {
+        let fs = filesystem();
+        let runner = Runner::new(&fs).with_solvers(solvers);
+
+        for timeout in [None, Some(Duration::from_secs(10))] {
+            let progress = Progress::new();
+            let options = RunOptions { progress: Some(progress.clone()), timeout, ..Default::default() };
+            runner.solve(&Day::Day01, &Part::Part1, options).unwrap();
+
+            assert_eq!(progress.snapshot(), State { label: "lines".into(), total: 2, current: 2 });
+        }
+    }
+
+    #[test]
+    fn cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-run-cache-{}", std::process::id()));
+        let fs = filesystem();
+        let runner = Runner::new(&fs).with_solvers(solvers).with_cache(Cache::new(&dir, "test"));
+        let solve = |cache, variant: Option<&str>| {
+            let options = RunOptions { cache, variant: variant.map(|v| v.to_string()), ..Default::default() };
+            runner.solve(&Day::Day01, &Part::Part2, options).unwrap()
+        };
+
+        // Nothing is stored yet.
+        assert!(!solve(CacheMode::Use, None).cached);
+        let report = solve(CacheMode::Use, None);
+        assert!(report.cached);
+        assert_eq!(report.answer, "3");
+
+        assert!(!solve(CacheMode::Store, None).cached);
+        assert!(!solve(CacheMode::Disabled, None).cached);
+        assert!(!solve(CacheMode::Use, Some("other")).cached);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,23 @@
+#!/bin/sh
+# Stand-in for an external solver, called as: external_solver.sh <day> <part>
+#
+#   day 01: part 1 counts the lines, part 2 sums them
+#   day 02: never finishes in time
+#   day 03: fails with exit code 3
+
+case "$1" in
+    01)
+        if [ "$2" = "1" ]; then
+            wc -l | tr -d ' '
+        else
+            awk '{ sum += $1 } END { print sum }'
+        fi
+        ;;
+    02)
+        exec sleep 10
+        ;;
+    03)
+        echo "something went wrong" >&2
+        exit 3
+        ;;
+esac
@@ -0,0 +1,42 @@
+//! Example solver plugin.
+//!
+//! Build it and copy the library into the plugin directory to make the
+//! runner pick it up:
+//!
+//! ```bash
+//! cargo build -p example_plugin
+//! mkdir -p plugins && cp target/debug/libexample_plugin.so plugins/
+//! cargo run -- --day=17 --part=1
+//! ```
+
+aoc::declare_plugin!("example", 17 => LineCounter::new);
+
+/// Not a real solution: part 1 counts the lines, part 2 the characters.
+#[derive(Default)]
+pub struct LineCounter {
+    input: aoc::input::Input,
+}
+
+impl aoc::Solver for LineCounter {
+    fn name(&self) -> &'static str {
+        "example_plugin::LineCounter"
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        self.input = input.clone();
+    }
+
+    fn part1(&mut self) -> String {
+        format!("{}", self.input.lines().count())
+    }
+
+    fn part2(&mut self) -> String {
+        format!("{}", self.input.lines().map(|line| line.len()).sum::<usize>())
+    }
+}
+
+impl LineCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
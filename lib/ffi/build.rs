@@ -0,0 +1,12 @@
+fn main() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    // The checked-in include/aoc.h is compared with this one by the tests.
+    cbindgen::generate(&crate_dir)
+        .expect("unable to generate C header")
+        .write_to_file(format!("{}/aoc.h", out_dir));
+}
@@ -0,0 +1,46 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated with cbindgen from lib/ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  AOC_STATUS_UNKNOWN_PART = 2,
+  AOC_STATUS_INVALID_INPUT = 3,
+  // The solver panicked, `answer` has the reason.
+  AOC_STATUS_PANIC = 4,
+  AOC_STATUS_NOT_IMPLEMENTED = 5,
+  // The solver returned an error, `answer` has the reason.
+  AOC_STATUS_ERROR = 6,
+} AocStatus;
+
+// Result of [`aoc_solve`]. `answer` is the answer on [`AocStatus::Ok`] and
+// the error message otherwise. It is never null and has to be released with
+// [`aoc_free`].
+typedef struct AocResult {
+  enum AocStatus status;
+  char *answer;
+} AocResult;
+
+// Solve `part` (1 or 2) of `day` (1-25). `input` is the puzzle input as
+// UTF-8 with `\n` line endings, `len` is its length in bytes.
+//
+// # Safety
+//
+// `input` has to point to `len` readable bytes; it can be null if `len` is 0.
+struct AocResult aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, uintptr_t len);
+
+// Release an answer returned by [`aoc_solve`]. Null is ignored.
+//
+// # Safety
+//
+// `answer` has to come from [`aoc_solve`] and must not be used afterwards.
+void aoc_free(char *answer);
+
+#endif /* AOC_H */
@@ -0,0 +1,93 @@
+//! C ABI for the solutions, the header is in `include/aoc.h`.
+//!
+//! ```c
+//! #include "aoc.h"
+//!
+//! AocResult result = aoc_solve(1, 1, (const uint8_t *)input, strlen(input));
+//! if (result.status == AOC_STATUS_OK) {
+//!     printf("%s\n", result.answer);
+//! } else {
+//!     fprintf(stderr, "error: %s\n", result.answer);
+//! }
+//! aoc_free(result.answer);
+//! ```
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use aoc::args::day::Day;
+use aoc::plugin;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AocStatus {
+    Ok = 0,
+    UnknownDay = 1,
+    UnknownPart = 2,
+    InvalidInput = 3,
+    /// The solver panicked, `answer` has the reason.
+    Panic = 4,
+    NotImplemented = 5,
+    /// The solver returned an error, `answer` has the reason.
+    Error = 6,
+}
+
+/// Result of [`aoc_solve`]. `answer` is the answer on [`AocStatus::Ok`] and
+/// the error message otherwise. It is never null and has to be released with
+/// [`aoc_free`].
+#[repr(C)]
+pub struct AocResult {
+    pub status: AocStatus,
+    pub answer: *mut c_char,
+}
+
+/// Solve `part` (1 or 2) of `day` (1-25). `input` is the puzzle input as
+/// UTF-8 with `\n` line endings, `len` is its length in bytes.
+///
+/// # Safety
+///
+/// `input` has to point to `len` readable bytes; it can be null if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u8, part: u8, input: *const u8, len: usize) -> AocResult {
+    let day = match Day::from_str(&format!("{}", day)) {
+        Ok(day) => day,
+        Err(err) => return result(AocStatus::UnknownDay, err),
+    };
+
+    if input.is_null() && len > 0 {
+        return result(AocStatus::InvalidInput, "input is null".into());
+    }
+
+    let solver = solution::get_solver(&day);
+    if !solver.is_implemented() {
+        return result(AocStatus::NotImplemented, format!("day {} is not implemented", day));
+    }
+
+    let mut answer: *mut c_char = std::ptr::null_mut();
+    let status = match plugin::solve(solver, part, input, len, &mut answer) {
+        plugin::STATUS_OK => AocStatus::Ok,
+        plugin::STATUS_UNKNOWN_PART => AocStatus::UnknownPart,
+        plugin::STATUS_INVALID_INPUT => AocStatus::InvalidInput,
+        plugin::STATUS_ERROR => AocStatus::Error,
+        _ => AocStatus::Panic,
+    };
+
+    AocResult { status, answer }
+}
+
+/// Release an answer returned by [`aoc_solve`]. Null is ignored.
+///
+/// # Safety
+///
+/// `answer` has to come from [`aoc_solve`] and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free(answer: *mut c_char) {
+    plugin::free(answer)
+}
+
+fn result(status: AocStatus, message: String) -> AocResult {
+    AocResult {
+        status,
+        answer: CString::new(message).unwrap().into_raw(),
+    }
+}
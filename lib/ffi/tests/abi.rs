@@ -0,0 +1,132 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use libloading::{Library, Symbol};
+
+// Mirror of the header, the test goes through the C ABI only.
+#[repr(C)]
+struct AocResult {
+    status: i32,
+    answer: *mut c_char,
+}
+
+type SolveFn = unsafe extern "C" fn(u8, u8, *const u8, usize) -> AocResult;
+type FreeFn = unsafe extern "C" fn(*mut c_char);
+
+const AOC_STATUS_OK: i32 = 0;
+const AOC_STATUS_UNKNOWN_DAY: i32 = 1;
+const AOC_STATUS_UNKNOWN_PART: i32 = 2;
+const AOC_STATUS_INVALID_INPUT: i32 = 3;
+const AOC_STATUS_PANIC: i32 = 4;
+const AOC_STATUS_NOT_IMPLEMENTED: i32 = 5;
+
+/// Header generated by the build script from the current sources.
+const GENERATED_HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/aoc.h"));
+
+fn library() -> Library {
+    // Integration tests live next to the libraries of the package.
+    let name = format!("{}aoc_ffi{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX);
+    let path = std::env::current_exe().unwrap().parent().unwrap().join(name);
+
+    unsafe { Library::new(path) }.expect("unable to load the built library")
+}
+
+fn solve(day: u8, part: u8, input: &[u8]) -> (i32, String) {
+    let library = library();
+
+    unsafe {
+        let solve: Symbol<SolveFn> = library.get(b"aoc_solve").unwrap();
+        let free: Symbol<FreeFn> = library.get(b"aoc_free").unwrap();
+
+        let result = solve(day, part, input.as_ptr(), input.len());
+        assert!(!result.answer.is_null());
+
+        let answer = CStr::from_ptr(result.answer).to_string_lossy().to_string();
+        free(result.answer);
+
+        (result.status, answer)
+    }
+}
+
+fn fixture(name: &str) -> Vec<u8> {
+    std::fs::read(format!("../solution/tests/fixtures/{}", name)).unwrap()
+}
+
+#[test]
+fn solve_day01() {
+    assert_eq!(solve(1, 1, &fixture("day01")), (AOC_STATUS_OK, "24000".to_string()));
+    assert_eq!(solve(1, 2, &fixture("day01")), (AOC_STATUS_OK, "45000".to_string()));
+}
+
+#[test]
+fn solve_day05() {
+    assert_eq!(solve(5, 1, &fixture("day05")), (AOC_STATUS_OK, "CMZ".to_string()));
+}
+
+#[test]
+fn unknown_day() {
+    assert_eq!(solve(26, 1, b""), (AOC_STATUS_UNKNOWN_DAY, "unable to parse day: 26".to_string()));
+}
+
+#[test]
+fn unknown_part() {
+    assert_eq!(solve(1, 3, b""), (AOC_STATUS_UNKNOWN_PART, "unable to parse part: 3".to_string()));
+}
+
+#[test]
+fn not_implemented() {
+    assert_eq!(solve(25, 1, b""), (AOC_STATUS_NOT_IMPLEMENTED, "day 25 is not implemented".to_string()));
+}
+
+#[test]
+fn invalid_input() {
+    let (status, _) = solve(1, 1, &[0xff, 0xfe]);
+
+    assert_eq!(status, AOC_STATUS_INVALID_INPUT);
+}
+
+#[test]
+fn null_input() {
+    let library = library();
+
+    unsafe {
+        let solve: Symbol<SolveFn> = library.get(b"aoc_solve").unwrap();
+        let free: Symbol<FreeFn> = library.get(b"aoc_free").unwrap();
+
+        let result = solve(1, 1, std::ptr::null(), 10);
+        assert_eq!(result.status, AOC_STATUS_INVALID_INPUT);
+        free(result.answer);
+    }
+}
+
+#[test]
+fn solver_panic() {
+    let (status, answer) = solve(1, 1, b"not a number");
+
+    assert_eq!(status, AOC_STATUS_PANIC);
+    assert!(answer.contains("InvalidDigit"), "{}", answer);
+}
+
+#[test]
+fn header() {
+    let header = std::fs::read_to_string("include/aoc.h").unwrap();
+
+    assert!(header.contains("AocResult aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, uintptr_t len);"), "{}", header);
+    assert!(header.contains("void aoc_free(char *answer);"), "{}", header);
+    assert!(header.contains("AOC_STATUS_NOT_IMPLEMENTED = 5"), "{}", header);
+}
+
+#[test]
+fn header_up_to_date() {
+    let header = std::fs::read_to_string("include/aoc.h").unwrap();
+
+    if std::env::var_os("UPDATE_HEADER").is_some() {
+        std::fs::write("include/aoc.h", GENERATED_HEADER).unwrap();
+        return;
+    }
+
+    assert!(
+        header == GENERATED_HEADER,
+        "include/aoc.h is out of date, update it with UPDATE_HEADER=1 cargo test -p aoc_ffi --test abi header_up_to_date",
+    );
+}
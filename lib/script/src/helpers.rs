@@ -0,0 +1,201 @@
+use rhai::{Array, Dynamic, Engine, EvalAltResult, FnPtr, NativeCallContext};
+
+use aoc::grid_helper;
+use aoc::math::Vec2D;
+use aoc::range::Range;
+use aoc::range_pair::RangePair;
+
+use crate::priority_queue::PriorityQueue;
+
+type Point = Vec2D<i64>;
+
+/// Register the `aoc` helpers on a script engine.
+///
+/// | Rust                          | Script                                        |
+/// |-------------------------------|-----------------------------------------------|
+/// | `Vec2D<i64>`                  | `Vec2D(x, y)`, `.x`, `.y`, `neighbors()`, `manhattan_to(p)`, `closest(points)` |
+/// | `Range<i64>`                  | `Range(from, to)`, `parse_range(s)`, `from()`, `to()`, `size()`, `contains(r)`, `overlap(r)` |
+/// | `RangePair<i64>`              | `parse_range_pair(s)`, `fst()`, `snd()`, `useless()`, `overlap()` |
+/// | `grid_helper`                 | `min_coordinates(a, b)`, `max_coordinates(a, b)`, `min_max_coordinates(points)`, `display(min, max, Fn)` |
+/// | `parse`                       | `integers(line)`, `blocks(lines)`             |
+/// | `BinaryHeap` (min-priority)   | `PriorityQueue()`, `push(priority, item)`, `pop()`, `len()`, `is_empty()` |
+pub fn register(engine: &mut Engine) {
+    register_vec2d(engine);
+    register_range(engine);
+    register_grid_helper(engine);
+    register_parse(engine);
+    register_priority_queue(engine);
+}
+
+fn register_vec2d(engine: &mut Engine) {
+    engine.register_type_with_name::<Point>("Vec2D")
+        .register_fn("Vec2D", Point::new)
+        .register_get_set("x", |p: &mut Point| p.x, |p: &mut Point, x: i64| p.x = x)
+        .register_get_set("y", |p: &mut Point| p.y, |p: &mut Point, y: i64| p.y = y)
+        .register_fn("neighbors", |p: &mut Point| to_array(p.neighbors()))
+        .register_fn("manhattan_to", |p: &mut Point, other: Point| p.manhattan_to(&other))
+        .register_fn("closest", |p: &mut Point, others: Array| -> Result<Point, Box<EvalAltResult>> {
+            let others = from_array::<Point>(others, "Vec2D")?;
+            if others.is_empty() {
+                return Err("closest: empty list".into());
+            }
+
+            Ok(p.closest(&others))
+        })
+        .register_fn("==", |a: &mut Point, b: Point| *a == b)
+        .register_fn("!=", |a: &mut Point, b: Point| *a != b)
+        .register_fn("to_string", |p: &mut Point| format!("({}, {})", p.x, p.y))
+        .register_fn("to_debug", |p: &mut Point| format!("Vec2D({}, {})", p.x, p.y));
+}
+
+fn register_range(engine: &mut Engine) {
+    engine.register_type_with_name::<Range<i64>>("Range")
+        .register_fn("Range", Range::<i64>::new)
+        .register_fn("parse_range", |s: &str| -> Result<Range<i64>, Box<EvalAltResult>> {
+            s.parse::<Range<i64>>().map_err(|err| err.into())
+        })
+        .register_fn("from", |r: &mut Range<i64>| r.from())
+        .register_fn("to", |r: &mut Range<i64>| r.to())
+        .register_fn("size", |r: &mut Range<i64>| r.size())
+        .register_fn("contains", |r: &mut Range<i64>, other: Range<i64>| r.contains(&other))
+        .register_fn("overlap", |r: &mut Range<i64>, other: Range<i64>| r.overlap(&other))
+        .register_fn("==", |a: &mut Range<i64>, b: Range<i64>| *a == b)
+        .register_fn("to_string", |r: &mut Range<i64>| format!("{}-{}", r.from(), r.to()));
+
+    engine.register_type_with_name::<RangePair<i64>>("RangePair")
+        .register_fn("parse_range_pair", |s: &str| -> Result<RangePair<i64>, Box<EvalAltResult>> {
+            s.parse::<RangePair<i64>>().map_err(|err| err.into())
+        })
+        .register_fn("fst", |p: &mut RangePair<i64>| p.fst().clone())
+        .register_fn("snd", |p: &mut RangePair<i64>| p.snd().clone())
+        .register_fn("useless", |p: &mut RangePair<i64>| match p.useless() {
+            Some(range) => Dynamic::from(range),
+            None => Dynamic::UNIT,
+        })
+        .register_fn("overlap", |p: &mut RangePair<i64>| p.overlap());
+}
+
+fn register_grid_helper(engine: &mut Engine) {
+    engine
+        .register_fn("min_coordinates", grid_helper::min_coordinates::<i64>)
+        .register_fn("max_coordinates", grid_helper::max_coordinates::<i64>)
+        .register_fn("min_max_coordinates", |points: Array| -> Result<Array, Box<EvalAltResult>> {
+            let (min, max) = grid_helper::min_max_coordinates(from_array::<Point>(points, "Vec2D")?);
+
+            Ok(vec![Dynamic::from(min), Dynamic::from(max)])
+        })
+        .register_fn("display", |ctx: NativeCallContext, min: Point, max: Point, show: FnPtr| -> Result<Array, Box<EvalAltResult>> {
+            let mut rows = Array::new();
+
+            for y in min.y..=max.y {
+                let mut row = String::new();
+                for x in min.x..=max.x {
+                    row.push(show.call_within_context::<char>(&ctx, (Point::new(x, y),))?);
+                }
+                rows.push(row.into());
+            }
+
+            Ok(rows)
+        });
+}
+
+fn register_parse(engine: &mut Engine) {
+    engine
+        .register_fn("integers", |line: &str| to_array(aoc::parse::integers(line)))
+        .register_fn("blocks", |lines: Array| -> Result<Array, Box<EvalAltResult>> {
+            let lines = from_array::<String>(lines, "string")?;
+
+            Ok(aoc::parse::blocks(&lines).into_iter().map(|block| to_array(block).into()).collect())
+        });
+}
+
+fn register_priority_queue(engine: &mut Engine) {
+    engine.register_type_with_name::<PriorityQueue>("PriorityQueue")
+        .register_fn("PriorityQueue", PriorityQueue::new)
+        .register_fn("push", PriorityQueue::push)
+        .register_fn("pop", PriorityQueue::pop)
+        .register_fn("len", |q: &mut PriorityQueue| q.len() as i64)
+        .register_fn("is_empty", |q: &mut PriorityQueue| q.is_empty());
+}
+
+fn to_array<T: Clone + Send + Sync + 'static>(list: Vec<T>) -> Array {
+    list.into_iter().map(Dynamic::from).collect()
+}
+
+fn from_array<T: Clone + 'static>(list: Array, type_name: &str) -> Result<Vec<T>, Box<EvalAltResult>> {
+    list.into_iter()
+        .map(|item| item.try_cast::<T>().ok_or_else(|| format!("expected a list of {}", type_name).into()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rhai::{Engine, Array};
+
+    fn engine() -> Engine {
+        let mut engine = Engine::new();
+        super::register(&mut engine);
+        engine
+    }
+
+    fn eval(script: &str) -> String {
+        engine().eval::<rhai::Dynamic>(script).unwrap().to_string()
+    }
+
+    #[test]
+    fn vec2d() {
+        assert_eq!(eval("let p = Vec2D(1, 2); p.x += 2; p.y"), "2");
+        assert_eq!(eval("Vec2D(1, 2).manhattan_to(Vec2D(4, -2))"), "7");
+        assert_eq!(eval("Vec2D(4, 8).neighbors().len()"), "4");
+        assert_eq!(eval("Vec2D(10, 10).closest([Vec2D(1, 4), Vec2D(3, 4)]).to_string()"), "(3, 4)");
+        assert_eq!(eval("Vec2D(1, 2) == Vec2D(1, 2)"), "true");
+        assert!(engine().eval::<bool>("Vec2D(1, 2).closest([1])").is_err());
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(eval("Range(2, 4).size()"), "3");
+        assert_eq!(eval("parse_range(\"1-5\").contains(Range(2, 4))"), "true");
+        assert_eq!(eval("parse_range(\"8-2\").to_string()"), "2-8");
+        assert_eq!(eval("parse_range_pair(\"5-7,7-9\").overlap()"), "true");
+        assert_eq!(eval("parse_range_pair(\"2-8,3-7\").useless().to_string()"), "3-7");
+        assert_eq!(eval("parse_range_pair(\"2-4,6-8\").useless() == ()"), "true");
+
+        let err = engine().eval::<bool>("parse_range(\"a-8\")").unwrap_err();
+        assert!(err.to_string().contains("invalid range: a-8"), "{}", err);
+    }
+
+    #[test]
+    fn grid_helper() {
+        assert_eq!(eval("min_coordinates(Vec2D(10, 10), Vec2D(15, -3)).to_string()"), "(10, -3)");
+        assert_eq!(eval("max_coordinates(Vec2D(10, 10), Vec2D(15, -3)).to_string()"), "(15, 10)");
+        assert_eq!(eval("min_max_coordinates([Vec2D(10, 10), Vec2D(-3, 5)])[0].to_string()"), "(-3, 5)");
+
+        let output = engine().eval::<Array>(
+            "display(Vec2D(-1, -1), Vec2D(1, 1), |p| if p.x == 0 || p.y == 0 { '#' } else { '.' })"
+        ).unwrap();
+        let output = output.into_iter().map(|row| row.into_string().unwrap()).collect::<Vec<String>>();
+        assert_eq!(output, vec![".#.", "###", ".#."]);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(eval("integers(\"move 1 from -2 to 3\")"), "[1, -2, 3]");
+        assert_eq!(eval("blocks([\"1\", \"2\", \"\", \"3\"]).len()"), "2");
+    }
+
+    #[test]
+    fn priority_queue() {
+        let script = r#"
+            let queue = PriorityQueue();
+            queue.push(3, "c");
+            queue.push(1, "a");
+            queue.push(2, "b");
+            let out = "";
+            while !queue.is_empty() { out += queue.pop(); }
+            out
+        "#;
+
+        assert_eq!(eval(script), "abc");
+    }
+}
@@ -0,0 +1,237 @@
+pub mod helpers;
+pub mod priority_queue;
+
+use rhai::{Array, Dynamic, Engine, Scope, AST};
+
+use aoc::Solver;
+use aoc::args::part::Part;
+use aoc::input::Input;
+
+const MAX_EXPR_DEPTH: usize = 64;
+const MAX_FUNCTION_EXPR_DEPTH: usize = 32;
+const MAX_CALL_LEVELS: usize = 64;
+
+/// Solver backed by a [Rhai](https://rhai.rs) script.
+///
+/// The script has to define `part1(input)` and `part2(input)`. If it also
+/// defines `parse(lines)`, that is called once and its return value is the
+/// `input` of both parts, otherwise `input` is the list of lines. The `aoc`
+/// helpers are available in the script, see [`helpers::register`].
+///
+/// # Example
+///
+/// ```
+/// use aoc::Solver;
+///
+/// let source = r#"
+///     fn parse(lines) { lines.map(|line| line.parse_int()) }
+///     fn part1(input) { input.reduce(|sum, v| sum + v, 0) }
+///     fn part2(input) { input.len() }
+/// "#;
+///
+/// let mut solver = script::ScriptSolver::new("example.rhai", source).unwrap();
+/// solver.read_input(&aoc::input::Input::from("10\n20\n"));
+///
+/// assert_eq!(solver.part1(), "30");
+/// assert_eq!(solver.part2(), "2");
+/// ```
+pub struct ScriptSolver {
+    name: String,
+    engine: Engine,
+    ast: AST,
+    lines: Vec<String>,
+    input: Option<Dynamic>,
+}
+
+impl Solver for ScriptSolver {
+    fn name(&self) -> &'static str {
+        "ScriptSolver"
+    }
+
+    fn read_input(&mut self, input: &Input) {
+        self.lines = input.lines().map(String::from).collect();
+        self.input = None;
+    }
+
+    fn part1(&mut self) -> String {
+        match self.solve(&Part::Part1) {
+            Ok(answer) => answer,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn part2(&mut self) -> String {
+        match self.solve(&Part::Part2) {
+            Ok(answer) => answer,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn solve(&mut self, part: &Part) -> Result<String, String> {
+        let input = self.input()?;
+        let function = format!("part{}", part);
+
+        let answer = self.engine
+            .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, &function, (input,))
+            .map_err(|err| format!("{}: {}: {}", self.name, function, err))?;
+
+        Ok(answer.to_string())
+    }
+}
+
+impl ScriptSolver {
+    /// Compile `source`. `name` is used in error messages, usually the path
+    /// of the script.
+    pub fn new(name: &str, source: &str) -> Result<Self, String> {
+        let mut engine = Engine::new();
+        // The limits of release builds, debug builds default to lower ones.
+        // Unlimited, a runaway recursion would overflow the native stack.
+        engine.set_max_expr_depths(MAX_EXPR_DEPTH, MAX_FUNCTION_EXPR_DEPTH);
+        engine.set_max_call_levels(MAX_CALL_LEVELS);
+        helpers::register(&mut engine);
+
+        let ast = engine.compile(source)
+            .map_err(|err| format!("{}: {}", name, err))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            engine,
+            ast,
+            lines: Vec::new(),
+            input: None,
+        })
+    }
+
+    fn input(&mut self) -> Result<Dynamic, String> {
+        if let Some(input) = &self.input {
+            return Ok(input.clone());
+        }
+
+        let lines: Array = self.lines.iter().cloned().map(Dynamic::from).collect();
+        let has_parse = self.ast.iter_functions().any(|f| f.name == "parse" && f.params.len() == 1);
+
+        let input = if has_parse {
+            self.engine
+                .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, "parse", (lines,))
+                .map_err(|err| format!("{}: parse: {}", self.name, err))?
+        } else {
+            lines.into()
+        };
+
+        self.input = Some(input.clone());
+
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solver;
+    use aoc::args::part::Part;
+    use aoc::io::{Filesystem, LocalFilesystem};
+
+    use super::ScriptSolver;
+
+    fn solver(source: &str, lines: Vec<&str>) -> ScriptSolver {
+        let mut solver = ScriptSolver::new("test.rhai", source).unwrap();
+        solver.read_input(&lines.into_iter().collect());
+        solver
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(solver("", vec![]).name(), "ScriptSolver")
+    }
+
+    #[test]
+    fn without_parse() {
+        let mut solver = solver("fn part1(lines) { lines[0] } fn part2(lines) { lines.len() }", vec!["a", "b"]);
+
+        assert_eq!(solver.part1(), "a");
+        assert_eq!(solver.part2(), "2");
+    }
+
+    #[test]
+    fn with_parse() {
+        let source = r#"
+            fn parse(lines) { lines.map(|l| l.parse_int()) }
+            fn part1(input) { input[0] }
+            fn part2(input) { input[1] }
+        "#;
+        let mut solver = solver(source, vec!["1", "2"]);
+
+        assert_eq!(solver.solve(&Part::Part1), Ok("1".to_string()));
+        assert_eq!(solver.solve(&Part::Part2), Ok("2".to_string()));
+    }
+
+    #[test]
+    fn compile_error() {
+        let err = ScriptSolver::new("broken.rhai", "fn part1(input) {\n    let x = ;\n}").err().unwrap();
+
+        assert!(err.starts_with("broken.rhai: "), "{}", err);
+        assert!(err.contains("line 2"), "{}", err);
+    }
+
+    #[test]
+    fn runtime_error() {
+        let mut solver = solver("fn part1(input) {\n    input[0]\n}\nfn part2(input) {\n\n    throw \"nope\";\n}", vec![]);
+
+        let err = solver.solve(&Part::Part1).unwrap_err();
+        assert!(err.starts_with("test.rhai: part1: "), "{}", err);
+        assert!(err.contains("line 2"), "{}", err);
+
+        let err = solver.solve(&Part::Part2).unwrap_err();
+        assert!(err.contains("nope"), "{}", err);
+        assert!(err.contains("line 6"), "{}", err);
+    }
+
+    #[test]
+    fn parse_error() {
+        let mut solver = solver("fn parse(lines) { lines[0].parse_int() } fn part1(x) { x }", vec!["abc"]);
+
+        let err = solver.solve(&Part::Part1).unwrap_err();
+        assert!(err.starts_with("test.rhai: parse: "), "{}", err);
+    }
+
+    #[test]
+    fn runaway_recursion() {
+        let mut solver = solver("fn down(n) { down(n + 1) } fn part1(input) { down(0) }", vec![]);
+        let err = solver.solve(&Part::Part1).unwrap_err();
+
+        assert!(err.starts_with("test.rhai: part1: "), "{}", err);
+        assert!(err.contains("Stack overflow"), "{}", err);
+    }
+
+    #[test]
+    fn deep_nesting() {
+        // Deeper than the defaults of debug builds allow.
+        let expr = format!("{}1{}", "(".repeat(14), ")".repeat(14));
+        let mut solver = solver(&format!("fn part1(input) {{ {} }}", expr), vec![]);
+
+        assert_eq!(solver.solve(&Part::Part1), Ok("1".to_string()));
+    }
+
+    #[test]
+    fn missing_part() {
+        let mut solver = solver("fn part1(input) { 1 }", vec![]);
+
+        assert!(solver.solve(&Part::Part2).unwrap_err().contains("part2"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn failing_part1() {
+        solver("fn part1(input) { throw \"nope\" }", vec![]).part1();
+    }
+
+    #[test]
+    fn example_day01() {
+        let fs = LocalFilesystem{};
+        let source = fs.read_file("../../scripts/day01.rhai").unwrap();
+        let mut solver = ScriptSolver::new("day01.rhai", source.as_str()).unwrap();
+        solver.read_input(&fs.read_file("../solution/tests/fixtures/day01").unwrap());
+
+        assert_eq!(solver.part1(), "24000");
+        assert_eq!(solver.part2(), "45000");
+    }
+}
@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use rhai::Dynamic;
+
+/// Min-priority queue for scripts. Items with the same priority come out in
+/// insertion order.
+#[derive(Default, Clone)]
+pub struct PriorityQueue {
+    heap: BinaryHeap<Entry>,
+    counter: u64,
+}
+
+#[derive(Clone)]
+struct Entry {
+    priority: i64,
+    order: u64,
+    item: Dynamic,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.order == other.order
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    // BinaryHeap is a max-heap, reverse everything to pop the lowest first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl PriorityQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, priority: i64, item: Dynamic) {
+        self.heap.push(Entry { priority, order: self.counter, item });
+        self.counter += 1;
+    }
+
+    /// Item with the lowest priority, or `()` if the queue is empty.
+    pub fn pop(&mut self) -> Dynamic {
+        match self.heap.pop() {
+            Some(entry) => entry.item,
+            None => Dynamic::UNIT,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rhai::Dynamic;
+
+    use super::PriorityQueue;
+
+    #[test]
+    fn order() {
+        let mut queue = PriorityQueue::new();
+        queue.push(5, Dynamic::from("five"));
+        queue.push(1, Dynamic::from("one"));
+        queue.push(3, Dynamic::from("three"));
+        queue.push(1, Dynamic::from("another one"));
+
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.pop().into_string().unwrap(), "one");
+        assert_eq!(queue.pop().into_string().unwrap(), "another one");
+        assert_eq!(queue.pop().into_string().unwrap(), "three");
+        assert_eq!(queue.pop().into_string().unwrap(), "five");
+        assert!(queue.is_empty());
+        assert!(queue.pop().is_unit());
+    }
+}
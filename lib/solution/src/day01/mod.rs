@@ -11,14 +11,14 @@
 /// use aoc::Solver;
 ///
 /// let mut solver = solution::day01::Solution::new();
-/// solver.read_lines(vec![
+/// solver.read_input(&vec![
 ///     "10", "20",
 ///     "",
 ///     "40", "20",
 ///     "",
 ///     "10", "15",
 ///     "",
-/// ].iter().map(|s| s.to_string()).collect());
+/// ].into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// let part2_solution = solver.part2();
@@ -33,22 +33,29 @@ impl aoc::Solver for Solution {
         "day01::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        let mut elfs: Vec<Elf> = Vec::new();
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Calorie Counting",
+            url: "https://adventofcode.com/2022/day/1",
+            description: "Find the Elf carrying the most Calories. How many total Calories is that Elf carrying?",
+            tags: &["parsing", "sorting"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
 
-        let mut elf: Elf = Elf::new();
-        for line in lines {
-            if line.is_empty() {
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        let mut elfs: Vec<Elf> = input.records()
+            .map(|record| {
+                let mut elf = Elf::new();
+                record.lines().for_each(|line| elf.add(line.parse().unwrap()));
                 elf.finalize();
-                elfs.push(elf);
-                elf = Elf::new();
-                continue
-            }
 
-            elf.add(line.parse().unwrap());
-        }
+                elf
+            })
+            .collect();
 
-        elfs.sort_by(|a, b| b.sum.cmp(&a.sum));
+        elfs.sort_by_key(|e| std::cmp::Reverse(e.sum));
 
         self.elves = elfs
     }
@@ -109,6 +116,7 @@ impl Elf {
 
 #[cfg(test)]
 mod tests {
+    use aoc::input::Input;
     use aoc::io::{Filesystem, LocalFilesystem};
     use aoc::Solver;
 
@@ -119,7 +127,7 @@ mod tests {
         assert_eq!(Solution::new().name(), "day01::Solution")
     }
 
-    fn lines() -> Vec<String> {
+    fn lines() -> Input {
         vec![
             "10", "20", "60", // 90
             "",
@@ -129,7 +137,7 @@ mod tests {
             "",
             "20", "20", "30", "40",  // 110
             "",
-        ].iter().map(|c| c.to_string() ).collect()
+        ].into_iter().collect()
     }
 
     #[test]
@@ -147,7 +155,7 @@ mod tests {
     #[test]
     fn solution_part1() {
         let mut solver = Solution::new();
-        solver.read_lines(lines());
+        solver.read_input(&lines());
 
         assert_eq!(solver.part1(), format!("{}", 200));
     }
@@ -155,7 +163,7 @@ mod tests {
     #[test]
     fn solution_part2() {
         let mut solver = Solution::new();
-        solver.read_lines(lines());
+        solver.read_input(&lines());
 
         assert_eq!(solver.part2(), format!("{}", 200 + 110 + 90));
     }
@@ -164,7 +172,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day01").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day01").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 24000));
     }
@@ -173,7 +181,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day01").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day01").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 45000));
     }
@@ -19,12 +19,12 @@ mod outcome;
 /// use aoc::Solver;
 ///
 /// let mut solver = solution::day02::Solution::new();
-/// solver.read_lines(vec![
+/// solver.read_input(&vec![
 ///     "A X",
 ///     "B Y",
 ///     "C Z",
 ///     "A Z",
-/// ].iter().map(|s| s.to_string()).collect());
+/// ].into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// let part2_solution = solver.part2();
@@ -39,9 +39,20 @@ impl aoc::Solver for Solution {
         "day02::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        let plays: Vec<Play> = lines
-            .iter()
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Rock Paper Scissors",
+            url: "https://adventofcode.com/2022/day/2",
+            description: "What would your total score be if everything goes exactly according to your strategy guide?",
+            tags: &["lookup"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        let plays: Vec<Play> = input
+            .lines()
             .map(|s| { Play::from_str(s).expect("input parse error") })
             .collect();
 
@@ -90,7 +101,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day02").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day02").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 15));
     }
@@ -99,7 +110,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day02").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day02").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 12));
     }
@@ -19,14 +19,14 @@ use std::collections::HashSet;
 /// use aoc::Solver;
 ///
 /// let mut solver = solution::day03::Solution::new();
-/// solver.read_lines(vec![
+/// solver.read_input(&vec![
 ///     "vJrwpWtwJgWrhcsFMMfFFhFp",
 ///     "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
 ///     "PmmdzqPrVvPwwTWBwg",
 ///     "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
 ///     "ttgJtRGJQctTZtZT",
 ///     "CrZsJsPPZsGzwwsLwLmpwMDw",
-/// ].iter().map(|s| s.to_string()).collect());
+/// ].into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// let part2_solution = solver.part2();
@@ -41,13 +41,24 @@ impl aoc::Solver for Solution {
         "day03::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        for line in lines {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Rucksack Reorganization",
+            url: "https://adventofcode.com/2022/day/3",
+            description: "Find the item type that appears in both compartments of each rucksack. What is the sum of the priorities of those item types?",
+            tags: &["sets"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        for line in input.byte_lines() {
             let compartment_length = line.len() / 2;
 
             let sack = Rucksack{
-                compartment1: line[..compartment_length].as_bytes().to_vec(),
-                compartment2: line[compartment_length..].as_bytes().to_vec(),
+                compartment1: line[..compartment_length].to_vec(),
+                compartment2: line[compartment_length..].to_vec(),
             };
 
             self.rucksacks.push(sack);
@@ -109,7 +120,7 @@ impl Rucksack {
     }
 
     fn full(&self) -> Vec<u8> {
-        self.compartment1.clone().into_iter().chain(self.compartment2.clone().into_iter()).collect()
+        self.compartment1.clone().into_iter().chain(self.compartment2.clone()).collect()
     }
 }
 
@@ -148,7 +159,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day03").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day03").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 157));
     }
@@ -157,7 +168,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day03").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day03").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 70));
     }
@@ -165,7 +176,7 @@ mod tests {
     #[test]
     fn common_item() {
         let cases: Vec<(Vec<&str>, Option<u8>)> = vec![
-            (vec!["asdfm", "mqwer", "zxmcv"], Some('m' as u8)),
+            (vec!["asdfm", "mqwer", "zxmcv"], Some(b'm')),
             (vec!["asdfm", "qwer", "zxmcv"], None),
         ];
 
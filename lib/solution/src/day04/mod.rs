@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use aoc::puzzle::{Adapter, Puzzle};
 use aoc::range_pair::RangePair;
 
 /// Day 4: Camp Cleanup
@@ -10,67 +11,69 @@ use aoc::range_pair::RangePair;
 /// # Example
 ///
 /// ```
-/// use aoc::Solver;
+/// use aoc::puzzle::Puzzle;
+/// use solution::day04::Solution;
 ///
-/// let mut solver = solution::day04::Solution::new();
-/// solver.read_lines(vec![
-///     "2-4,6-8",
-///     "2-3,4-5",
-///     "5-7,7-9",
-///     "2-8,3-7",
-///     "6-6,4-6",
-///     "2-6,4-8",
-/// ].iter().map(|s| s.to_string()).collect());
+/// let input = Solution::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n").unwrap();
 ///
-/// let part1_solution = solver.part1();
-/// let part2_solution = solver.part2();
+/// assert_eq!(Solution::part1(&input), "2");
+/// assert_eq!(Solution::part2(&input), "4");
 /// ```
-#[derive(Default)]
-pub struct Solution {
-    groups: Vec<RangePair<i32>>,
-}
-
-impl aoc::Solver for Solution {
-    fn name(&self) -> &'static str {
-        "day04::Solution"
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const NAME: &'static str = "day04::Solution";
+    type Input = Vec<RangePair<i32>>;
+
+    fn metadata() -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Camp Cleanup",
+            url: "https://adventofcode.com/2022/day/4",
+            description: "In how many assignment pairs does one range fully contain the other?",
+            tags: &["ranges"],
+            examples: 1,
+            ..Default::default()
+        }
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        let pairs: Vec<RangePair<i32>> = lines.iter()
-            .map(|s| {
-                RangePair::from_str(s).expect("parse error")
-            })
-            .collect();
-
-        self.groups = pairs;
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        input.lines().map(RangePair::from_str).collect()
     }
 
-    fn part1(&mut self) -> String {
-        let useless_pairs: usize = self.groups.iter().flat_map(|s| s.useless()).count();
+    fn part1(groups: &Self::Input) -> String {
+        let useless_pairs: usize = groups.iter().flat_map(|s| s.useless()).count();
 
         format!("{}", useless_pairs)
     }
 
-    fn part2(&mut self) -> String {
-        let overlap_pairs: usize = self.groups.iter().filter(|s| s.overlap()).count();
+    fn part2(groups: &Self::Input) -> String {
+        let overlap_pairs: usize = groups.iter().filter(|s| s.overlap()).count();
 
         format!("{}", overlap_pairs)
     }
 }
 
 impl Solution {
-    pub fn new() -> Self {
-        Self::default()
+    /// Solver of the day, like the other days.
+    pub fn new() -> Adapter<Self> {
+        Adapter::new()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use aoc::io::{Filesystem, LocalFilesystem};
+    use aoc::puzzle::Puzzle;
+    use aoc::range_pair::RangePair;
     use aoc::Solver;
 
     use super::Solution;
 
+    fn input() -> Vec<RangePair<i32>> {
+        let fs = LocalFilesystem{};
+        Solution::parse(fs.read_file("tests/fixtures/day04").unwrap().as_str()).unwrap()
+    }
+
     #[test]
     fn name() {
         assert_eq!(Solution::new().name(), "day04::Solution")
@@ -78,19 +81,16 @@ mod tests {
 
     #[test]
     fn example1_part1() {
-        let fs = LocalFilesystem{};
-        let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day04").unwrap());
-
-        assert_eq!(solver.part1(), format!("{}", 2));
+        assert_eq!(Solution::part1(&input()), format!("{}", 2));
     }
 
     #[test]
     fn example1_part2() {
-        let fs = LocalFilesystem{};
-        let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day04").unwrap());
+        assert_eq!(Solution::part2(&input()), format!("{}", 4));
+    }
 
-        assert_eq!(solver.part2(), format!("{}", 4));
+    #[test]
+    fn parse_error() {
+        assert!(Solution::parse("2-4,6-8\nnope\n").is_err());
     }
 }
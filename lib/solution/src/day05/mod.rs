@@ -30,7 +30,7 @@ mod instruction;
 /// ];
 ///
 /// let mut solver = solution::day05::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -53,7 +53,7 @@ mod instruction;
 /// ];
 ///
 /// let mut solver = solution::day05::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -68,8 +68,19 @@ impl aoc::Solver for Solution {
         "day05::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        for line in lines {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Supply Stacks",
+            url: "https://adventofcode.com/2022/day/5",
+            description: "After the rearrangement procedure completes, what crate ends up on top of each stack?",
+            tags: &["parsing", "simulation", "stacks"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        for line in input.lines() {
             if line.is_empty() {
                 continue
             }
@@ -79,7 +90,7 @@ impl aoc::Solver for Solution {
                 self.place_crates(line);
             } else if line.starts_with("move") {
                 // Parse operations
-                let value = Instruction::from_str(&line).expect("parse error");
+                let value = Instruction::from_str(line).expect("parse error");
                 self.instructions.push(value);
             }
         }
@@ -125,7 +136,7 @@ impl Solution {
     /// New empty solution.
     pub fn new() -> Self { Self::default() }
 
-    fn place_crates(&mut self, line: String) {
+    fn place_crates(&mut self, line: &str) {
         for (idx, chunk) in line.as_bytes().chunks(4).enumerate() {
 
             if self.stacks.len() <= idx {
@@ -166,7 +177,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day05").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day05").unwrap());
 
         assert_eq!(solver.part1(), "CMZ".to_string());
     }
@@ -175,7 +186,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day05").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day05").unwrap());
 
         assert_eq!(solver.part2(), "MCD".to_string());
     }
@@ -17,9 +17,9 @@ impl FromStr for Crate {
     }
 }
 
-impl std::string::ToString for Crate {
-    fn to_string(&self) -> String {
-        format!("[{}]", self.0)
+impl std::fmt::Display for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self.0)
     }
 }
 
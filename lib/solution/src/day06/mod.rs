@@ -15,7 +15,7 @@ use std::collections::HashSet;
 /// let lines: Vec<&str> = vec!["bvwbjplbgvbhsrlpgdmjqwftvncz"];
 ///
 /// let mut solver = solution::day06::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -28,7 +28,7 @@ use std::collections::HashSet;
 /// let lines: Vec<&str> = vec!["bvwbjplbgvbhsrlpgdmjqwftvncz"];
 ///
 /// let mut solver = solution::day06::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -42,8 +42,19 @@ impl aoc::Solver for Solution {
         "day06::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        if let Some(signal) = lines.first() {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Tuning Trouble",
+            url: "https://adventofcode.com/2022/day/6",
+            description: "How many characters need to be processed before the first start-of-packet marker is detected?",
+            tags: &["sliding-window"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        if let Some(signal) = input.lines().next() {
             self.signal = signal.to_string();
         } else {
             panic!("invalid input")
@@ -88,19 +99,20 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use aoc::input::Input;
     use aoc::Solver;
 
     use super::Solution;
 
     fn solver_part1(input: &str, expected: &str) {
         let mut solver = Solution::new();
-        solver.read_lines(vec![input.to_string()]);
+        solver.read_input(&Input::from(input));
         assert_eq!(solver.part1(), expected.to_string())
     }
 
     fn solver_part2(input: &str, expected: &str) {
         let mut solver = Solution::new();
-        solver.read_lines(vec![input.to_string()]);
+        solver.read_input(&Input::from(input));
         assert_eq!(solver.part2(), expected.to_string())
     }
 
@@ -113,7 +125,7 @@ mod tests {
     #[should_panic]
     fn empty_input() {
         let mut solver = Solution::new();
-        solver.read_lines(vec![]);
+        solver.read_input(&Input::default());
     }
 
     #[test]
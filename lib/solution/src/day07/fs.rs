@@ -24,9 +24,9 @@ impl Directory {
             .collect()
     }
 
-    pub fn add_file(self, path: Vec<String>, name: String, size: u64) -> Self {
+    pub fn add_file(self, path: &[String], name: String, size: u64) -> Self {
         let (files, directories) = if path.is_empty() {
-            let files = self.files.iter().chain(vec![name].iter()).cloned().collect();
+            let files = self.files.iter().chain([name].iter()).cloned().collect();
 
             (files, self.directories)
         } else {
@@ -38,7 +38,7 @@ impl Directory {
                      if &d.name != head {
                          d
                      } else {
-                         d.add_file(tail.into(), name.clone(), size)
+                         d.add_file(tail, name.clone(), size)
                      }
                 )
                 .collect();
@@ -54,9 +54,9 @@ impl Directory {
         }
     }
 
-    pub fn add_dir(self, path: Vec<String>, dir: Self) -> Self {
+    pub fn add_dir(self, path: &[String], dir: Self) -> Self {
         let directories = if path.is_empty() {
-            self.directories.iter().chain(vec![dir].iter()).cloned().collect()
+            self.directories.iter().chain([dir].iter()).cloned().collect()
         } else {
             let (head, tail) = path.split_first().unwrap();
 
@@ -66,7 +66,7 @@ impl Directory {
                      if &d.name != head {
                          d
                      } else {
-                         d.add_dir(tail.into(), dir.clone())
+                         d.add_dir(tail, dir.clone())
                      }
                 )
                 .collect()
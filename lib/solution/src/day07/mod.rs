@@ -42,7 +42,7 @@ mod fs;
 /// ];
 ///
 /// let mut solver = solution::day07::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -79,7 +79,7 @@ mod fs;
 /// ];
 ///
 /// let mut solver = solution::day07::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -93,12 +93,23 @@ impl aoc::Solver for Solution {
         "day07::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "No Space Left On Device",
+            url: "https://adventofcode.com/2022/day/7",
+            description: "Find all of the directories with a total size of at most 100000. What is the sum of the total sizes of those directories?",
+            tags: &["parsing", "tree"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
         let mut root = fs::Directory::new("/".into());
 
         let mut path: Vec<String> = Vec::new();
 
-        for line in lines {
+        for line in input.lines() {
             if line.starts_with("$ ") {
                 match command::Command::from_str(line.strip_prefix("$ ").unwrap_or("")) {
                     Ok(command::Command::List) => continue,
@@ -114,12 +125,12 @@ impl aoc::Solver for Solution {
                     Err(err) => { eprintln!("error: {}", err); return }
                 };
             } else {
-                match fs::Entry::from_str(&line) {
+                match fs::Entry::from_str(line) {
                     Ok(fs::Entry::Directory(name)) => {
-                        root = root.add_dir(path.clone(), fs::Directory::new(name))
+                        root = root.add_dir(&path, fs::Directory::new(name))
                     },
                     Ok(fs::Entry::File(name, size)) => {
-                        root = root.add_file(path.clone(), name, size)
+                        root = root.add_file(&path, name, size)
                     },
                     Err(err) => { eprintln!("error: {}", err); return }
                 }
@@ -178,7 +189,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day07").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day07").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 95437));
     }
@@ -187,7 +198,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day07").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day07").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 24933642));
     }
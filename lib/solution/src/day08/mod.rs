@@ -22,7 +22,7 @@ mod forest;
 /// ];
 ///
 /// let mut solver = solution::day08::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -41,7 +41,7 @@ mod forest;
 /// ];
 ///
 /// let mut solver = solution::day08::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -55,22 +55,28 @@ impl aoc::Solver for Solution {
         "day08::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        let rows = lines.len();
-        let columns = if let Some(line) = lines.first() {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Treetop Tree House",
+            url: "https://adventofcode.com/2022/day/8",
+            description: "Consider your map; how many trees are visible from outside the grid?",
+            tags: &["grid"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        let rows = input.lines().count();
+        let columns = if let Some(line) = input.lines().next() {
             line.len()
         } else {
             return
         };
 
-        let trees = lines
-            .iter()
-            .flat_map(|line| {
-                line.clone().into_bytes()
-                    .iter()
-                    .map(|ch| ch - 0x30)
-                    .collect::<Vec<u8>>()
-            })
+        let trees = input
+            .byte_lines()
+            .flat_map(|line| line.iter().map(|ch| ch - 0x30))
             .collect::<Vec<u8>>()
             ;
 
@@ -98,7 +104,7 @@ impl aoc::Solver for Solution {
             .map(|v| (*v, self.forest.at(*v).unwrap()))
             .collect::<Vec<(usize, u8)>>();
 
-        visible_trees.sort_by(|a, b| a.0.cmp(&b.0));
+        visible_trees.sort_by_key(|a| a.0);
 
         format!("{}", visible.len())
     }
@@ -149,7 +155,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day08").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day08").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 21));
     }
@@ -158,7 +164,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day08").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day08").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 8));
     }
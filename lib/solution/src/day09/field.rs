@@ -59,7 +59,6 @@ impl std::fmt::Display for Rope {
         let rows = max_coordinate.y - min_coordinate.y + 1;
 
         let map = self.tail_history.union(&self.head_history)
-            .into_iter()
             .fold(vec!["."; (cols * rows).try_into().unwrap()], |m, c| {
                 let x = c.x - min_coordinate.x;
                 let y = c.y - min_coordinate.y;
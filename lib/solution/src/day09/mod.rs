@@ -29,7 +29,7 @@ mod field;
 /// ];
 ///
 /// let mut solver = solution::day09::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -51,7 +51,7 @@ mod field;
 /// ];
 ///
 /// let mut solver = solution::day09::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -65,8 +65,19 @@ impl aoc::Solver for Solution {
         "day09::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        self.motions = lines.iter()
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Rope Bridge",
+            url: "https://adventofcode.com/2022/day/9",
+            description: "Simulate your complete hypothetical series of motions. How many positions does the tail of the rope visit at least once?",
+            tags: &["simulation", "grid"],
+            examples: 2,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        self.motions = input.lines()
             .map(|line| match Movement::from_str(line) {
                 Ok(m) => m,
                 Err(err) => panic!("{}", err)
@@ -114,7 +125,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day09").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day09").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 13));
     }
@@ -123,7 +134,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day09-p2").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day09-p2").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 36));
     }
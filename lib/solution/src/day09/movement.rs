@@ -51,7 +51,6 @@ impl Movement {
             Movement::Right(_) => Movement::Right,
         };
         (0..self.lift())
-            .into_iter()
             .map(|_| f(1))
             .collect()
     }
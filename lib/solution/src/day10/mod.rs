@@ -24,7 +24,7 @@ mod instruction;
 /// ];
 ///
 /// let mut solver = solution::day10::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -41,7 +41,7 @@ mod instruction;
 /// ];
 ///
 /// let mut solver = solution::day10::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -55,13 +55,20 @@ impl aoc::Solver for Solution {
         "day10::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        self.instructions = lines.iter()
-            .filter_map(|s| if let Ok(v) = Instruction::from_str(s) {
-                Some(v)
-            } else {
-                None
-            })
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Cathode-Ray Tube",
+            url: "https://adventofcode.com/2022/day/10",
+            description: "Find the signal strength during the 20th, 60th, 100th, 140th, 180th, and 220th cycles. What is the sum of these six signal strengths?",
+            tags: &["simulation", "cpu"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        self.instructions = input.lines()
+            .filter_map(|s| Instruction::from_str(s).ok())
             .collect()
             ;
     }
@@ -106,7 +113,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day10").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day10").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 13140));
     }
@@ -115,7 +122,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day10").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day10").unwrap());
 
         assert_eq!(solver.part2(), format!("██..██..██..██..██..██..██..██..██..██..\n███...███...███...███...███...███...███.\n████....████....████....████....████....\n█████.....█████.....█████.....█████.....\n██████......██████......██████......████\n███████.......███████.......███████.....\n"));
     }
@@ -48,7 +48,7 @@ mod token;
 /// ];
 ///
 /// let mut solver = solution::day11::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -89,7 +89,7 @@ mod token;
 /// ];
 ///
 /// let mut solver = solution::day11::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -103,14 +103,22 @@ impl aoc::Solver for Solution {
         "day11::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-       self.monkeys = lines.into_iter()
-            .filter(|f| !f.is_empty())
-            .collect::<Vec<String>>()
-            .chunks(6)
-            .map(|block| {
-                block.iter().fold(Monkey::default(), |mut monkey, line| {
-                    monkey.parse_line(line.to_string());
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Monkey in the Middle",
+            url: "https://adventofcode.com/2022/day/11",
+            description: "What is the level of monkey business after 20 rounds of stuff-slinging simian shenanigans?",
+            tags: &["simulation", "modular-arithmetic"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        self.monkeys = input.records()
+            .map(|record| {
+                record.lines().fold(Monkey::default(), |mut monkey, line| {
+                    monkey.parse_line(line);
                     monkey
                 })
             })
@@ -123,7 +131,9 @@ impl aoc::Solver for Solution {
         let mut inspects: HashMap<i64, i64> = HashMap::new();
         let modi = monkeys.iter().map(|m| m.modi()).product();
 
+        aoc::progress::start("rounds", 20);
         for _ in 0..20 {
+            aoc::progress::tick();
             (0..monkeys.len()).for_each(|idx| {
                 let current = monkeys[idx].clone();
                 let (targets, monkey) = current.process(modi, true);
@@ -149,7 +159,9 @@ impl aoc::Solver for Solution {
         let mut inspects: HashMap<i64, i64> = HashMap::new();
         let modi = monkeys.iter().map(|m| m.modi()).product();
 
+        aoc::progress::start("rounds", 10000);
         for _ in 0..10000 {
+            aoc::progress::tick();
             (0..monkeys.len()).for_each(|idx| {
                 let current = monkeys[idx].clone();
                 let (targets, monkey) = current.process(modi, false);
@@ -209,7 +221,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day11").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day11").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 10605));
     }
@@ -218,8 +230,21 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day11").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day11").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 2713310158_i64));
     }
+
+    #[test]
+    fn progress_part2() {
+        let fs = LocalFilesystem{};
+        let mut solver = Solution::new();
+        solver.read_input(&fs.read_file("tests/fixtures/day11").unwrap());
+
+        let progress = aoc::progress::Progress::new();
+        aoc::progress::install(progress.clone());
+        solver.part2();
+
+        assert_eq!(format!("{}", progress.snapshot()), "rounds: 10000/10000 (100%)");
+    }
 }
@@ -43,7 +43,7 @@ impl Monkey {
         new_self
     }
 
-    pub fn parse_line(&mut self, line: String) {
+    pub fn parse_line(&mut self, line: &str) {
         if line.starts_with("Monkey") {
             let parts = line.split(' ').collect::<Vec<&str>>();
             self.id = parts[1].strip_suffix(':').unwrap().parse::<i64>().unwrap();
@@ -63,11 +63,7 @@ impl Monkey {
 
         if line.starts_with("  Operation:") {
             let ops: Vec<Token> = line.split(": ").last().unwrap()
-                .split(' ').filter_map(|f| if let Ok(v) = Token::from_str(f) {
-                    Some(v)
-                } else {
-                    None
-                })
+                .split(' ').filter_map(|f| Token::from_str(f).ok())
                 .collect();
             self.operation = Operation::new(ops);
 
@@ -76,7 +72,7 @@ impl Monkey {
 
         if line.starts_with("  Test:") {
             let test: i64 = line.split(": ").last().unwrap()
-                .split(' ').last().unwrap().parse::<i64>().unwrap();
+                .split(' ').next_back().unwrap().parse::<i64>().unwrap();
             self.test = test;
 
             return
@@ -84,7 +80,7 @@ impl Monkey {
 
         if line.starts_with("    If true:") {
             let answer: i64 = line.split(": ").last().unwrap()
-                .split(' ').last().unwrap().parse::<i64>().unwrap();
+                .split(' ').next_back().unwrap().parse::<i64>().unwrap();
             self.if_true = answer;
 
             return
@@ -92,7 +88,7 @@ impl Monkey {
 
         if line.starts_with("    If false:") {
             let answer: i64 = line.split(": ").last().unwrap()
-                .split(' ').last().unwrap().parse::<i64>().unwrap();
+                .split(' ').next_back().unwrap().parse::<i64>().unwrap();
             self.if_false = answer;
         }
     }
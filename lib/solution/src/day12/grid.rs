@@ -30,7 +30,7 @@ impl Grid {
 
 
         while let Some(state) = open_set.pop() {
-            if self.fields.get(&state.coordinate).is_none() {
+            if !self.fields.contains_key(&state.coordinate) {
                 println!(" >>> met a weird field: {:?}", state.coordinate);
                 continue
             }
@@ -27,7 +27,7 @@ mod state;
 /// ];
 ///
 /// let mut solver = solution::day12::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -46,7 +46,7 @@ mod state;
 /// ];
 ///
 /// let mut solver = solution::day12::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -62,9 +62,20 @@ impl aoc::Solver for Solution {
         "day12::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        for (y, line) in lines.into_iter().enumerate() {
-            for (x, ch) in line.into_bytes().iter().enumerate() {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Hill Climbing Algorithm",
+            url: "https://adventofcode.com/2022/day/12",
+            description: "What is the fewest steps required to move from your current position to the location that should get the best signal?",
+            tags: &["pathfinding", "grid"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        for (y, line) in input.byte_lines().enumerate() {
+            for (x, ch) in line.iter().enumerate() {
                 let pos = Vec2D::new(x as i64, y as i64);
                 let value = match *ch as char {
                     'S' => {
@@ -134,7 +145,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day12").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day12").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 31));
     }
@@ -143,7 +154,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day12").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day12").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 29));
     }
@@ -152,7 +163,7 @@ mod tests {
     fn impossible_map() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day12-impossible").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day12-impossible").unwrap());
 
         assert_eq!(solver.part1(), format!("solution not found"));
     }
@@ -23,7 +23,7 @@ use self::packet::Packet;
 /// ];
 ///
 /// let mut solver = solution::day13::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -42,7 +42,7 @@ use self::packet::Packet;
 /// ];
 ///
 /// let mut solver = solution::day13::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -58,14 +58,21 @@ impl aoc::Solver for Solution {
         "day13::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        self.packet_pairs = lines.into_iter()
-            .filter(|f| !f.is_empty())
-            .collect::<Vec<String>>()
-            .chunks(2)
-            .map(|block| match block {
-                [fst, snd] =>
-                    (Packet::from_string(fst.clone()), Packet::from_string(snd.clone())),
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Distress Signal",
+            url: "https://adventofcode.com/2022/day/13",
+            description: "Determine which pairs of packets are already in the right order. What is the sum of the indices of those pairs?",
+            tags: &["parsing", "recursion", "sorting"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        self.packet_pairs = input.records()
+            .map(|record| match record.lines().collect::<Vec<&str>>()[..] {
+                [fst, snd] => (Packet::from_string(fst.to_string()), Packet::from_string(snd.to_string())),
                 _ => unreachable!(),
             })
             .collect::<Vec<PacketPair>>();
@@ -132,7 +139,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day13").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day13").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 13));
     }
@@ -141,7 +148,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day13").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day13").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 140));
     }
@@ -150,7 +157,7 @@ mod tests {
     fn input_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("../../input/day13").unwrap());
+        solver.read_input(&fs.read_file("../../input/day13").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 25935));
     }
@@ -221,7 +221,7 @@ mod tests {
 
         while grid.cycle() {}
 
-        let expected = vec![
+        let expected = [
             "..+..",
             ".....",
             "#....",
@@ -20,7 +20,7 @@ mod grid;
 /// ];
 ///
 /// let mut solver = solution::day14::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -36,7 +36,7 @@ mod grid;
 /// ];
 ///
 /// let mut solver = solution::day14::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -50,8 +50,19 @@ impl aoc::Solver for Solution {
         "day14::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        for line in lines {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Regolith Reservoir",
+            url: "https://adventofcode.com/2022/day/14",
+            description: "Using your scan, simulate the falling sand. How many units of sand come to rest before sand starts flowing into the abyss below?",
+            tags: &["simulation", "grid"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        for line in input.lines() {
             let coordinates = line.split(" -> ")
                 .map(|part| {
                     let group = part
@@ -78,7 +89,9 @@ impl aoc::Solver for Solution {
 
     fn part1(&mut self) -> String {
         let mut cycles = 0;
+        aoc::progress::start("sand", 0);
         while self.cave.cycle() {
+            aoc::progress::tick();
             cycles += 1;
         }
 
@@ -94,7 +107,9 @@ impl aoc::Solver for Solution {
         );
 
         let mut cycles = 0;
+        aoc::progress::start("sand", 0);
         while self.cave.cycle() {
+            aoc::progress::tick();
             cycles += 1;
         }
 
@@ -123,7 +138,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day14").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day14").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 24));
     }
@@ -132,7 +147,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day14").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day14").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 93));
     }
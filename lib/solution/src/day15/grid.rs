@@ -90,7 +90,7 @@ mod tests {
         grid.add_probe(Vec2D::new(0, 0), Vec2D::new(3, 3));
         grid.add_probe(Vec2D::new(4, 4), Vec2D::new(4, 3));
 
-        let expected = vec![
+        let expected = [
             "S....",
             ".....",
             ".....",
@@ -24,7 +24,7 @@ mod grid;
 /// ];
 ///
 /// let mut solver = solution::day15::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -44,17 +44,26 @@ mod grid;
 /// ];
 ///
 /// let mut solver = solution::day15::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
-#[derive(Default)]
 pub struct Solution {
     grid: grid::Grid,
     target_line: i64,
     max_point: i64,
 }
 
+impl Default for Solution {
+    fn default() -> Self {
+        Solution {
+            grid: grid::Grid::default(),
+            target_line: 2000000,
+            max_point: 4000000,
+        }
+    }
+}
+
 fn parse_i64(s: &str) -> i64 {
     s.trim_matches(|f| f != '-' && !char::is_numeric(f))
         .parse::<i64>()
@@ -66,8 +75,22 @@ impl aoc::Solver for Solution {
         "day15::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        lines.into_iter()
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Beacon Exclusion Zone",
+            url: "https://adventofcode.com/2022/day/15",
+            description: "In the row where y=2000000, how many positions cannot contain a beacon?",
+            tags: &["geometry", "ranges"],
+            params: &[
+                aoc::metadata::Param { name: "target_line", description: "Row checked by part 1, 10 in the example", default: "2000000" },
+                aoc::metadata::Param { name: "max_point", description: "Bound of the search of part 2, 20 in the example", default: "4000000" },
+            ],
+            examples: 1,
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        input.lines()
             .for_each(|line| {
                 let parts = line.split(' ').collect::<Vec<&str>>();
                 let probe_x = parse_i64(parts.get(2).unwrap());
@@ -77,9 +100,19 @@ impl aoc::Solver for Solution {
 
                 self.grid.add_probe(Vec2D::new(probe_x, probe_y), Vec2D::new(beacon_x, beacon_y));
             });
+    }
+
+    /// `target_line` (part 1) and `max_point` (part 2), the examples use 10
+    /// and 20.
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let value = value.parse::<i64>().map_err(|_| format!("{} should be a number, not {}", name, value))?;
+        match name {
+            "target_line" => self.target_line = value,
+            "max_point" => self.max_point = value,
+            _ => return Err(format!("unknown parameter: {}", name)),
+        }
 
-        self.target_line = 2000000;
-        self.max_point = 4000000;
+        Ok(())
     }
 
     fn part1(&mut self) -> String {
@@ -93,7 +126,7 @@ impl aoc::Solver for Solution {
 
                 Some(Range::new(min, max))
             }).collect::<Vec<Range<i64>>>();
-        let range = ranges.iter().fold(ranges.get(0).unwrap().clone(), |c, r| {
+        let range = ranges.iter().fold(ranges.first().unwrap().clone(), |c, r| {
             let min = if r.from() < c.from() { r.from() } else { c.from() };
             let max = if r.to() > c.to() { r.to() } else { c.to() };
 
@@ -111,7 +144,13 @@ impl aoc::Solver for Solution {
     }
 
     fn part2(&mut self) -> String {
+        aoc::progress::start("rows", self.max_point as u64 + 1);
         for y in 0..=self.max_point {
+            if aoc::cancel::is_cancelled() {
+                break
+            }
+            aoc::progress::tick();
+
             let mut x = 0;
             loop {
                 let current = Vec2D::new(x, y);
@@ -166,7 +205,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day15").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day15").unwrap());
 
         solver.target_line = 10;
 
@@ -177,9 +216,36 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day15").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day15").unwrap());
         solver.max_point = 20;
 
         assert_eq!(solver.part2(), format!("{}", 56000011));
     }
+
+    #[test]
+    fn params() {
+        let fs = LocalFilesystem{};
+        let mut solver = Solution::new();
+        solver.set_param("target_line", "10").unwrap();
+        solver.set_param("max_point", "20").unwrap();
+        solver.read_input(&fs.read_file("tests/fixtures/day15").unwrap());
+
+        assert_eq!(solver.part1(), format!("{}", 26));
+        assert_eq!(solver.part2(), format!("{}", 56000011));
+        assert!(solver.set_param("max_point", "far").is_err());
+        assert!(solver.set_param("speed", "1").is_err());
+    }
+
+    #[test]
+    fn cancelled_part2() {
+        let fs = LocalFilesystem{};
+        let mut solver = Solution::new();
+        solver.read_input(&fs.read_file("tests/fixtures/day15").unwrap());
+
+        let token = aoc::cancel::Token::new();
+        token.cancel();
+        aoc::cancel::install(token);
+
+        assert_eq!(solver.part2(), format!("{}", 0));
+    }
 }
@@ -22,7 +22,7 @@ impl std::str::FromStr for Valve {
     type Err = String;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let parts = line.splitn(10, ' ').into_iter().collect::<Vec<&str>>();
+        let parts = line.splitn(10, ' ').collect::<Vec<&str>>();
         let name = parts.get(1)
             .ok_or(format!("unable to parse line: {}", line))?
             .to_string();
@@ -108,8 +108,8 @@ impl Cave {
     }
 
     pub fn prepare(&mut self) {
-        self.flowing = self.valves.iter()
-            .filter_map(|(_, valve)| if valve.rate > 0 { Some(valve) } else { None } )
+        self.flowing = self.valves.values()
+            .filter(|valve| valve.rate > 0)
             .cloned()
             .collect();
 
@@ -153,6 +153,11 @@ impl Cave {
                 .and_modify(|value| *value = current_max.max(*value))
                 .or_insert(current_max);
 
+            // The state is recorded first, so a cancelled walk still has one.
+            if aoc::cancel::is_cancelled() {
+                break
+            }
+
             if state.valves == self.max_mask || state.remaining_time <= 0 {
                 continue
             }
@@ -32,7 +32,7 @@ const TIMEOUT: i32 = 30;
 /// ];
 ///
 /// let mut solver = solution::day16::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -56,7 +56,7 @@ const TIMEOUT: i32 = 30;
 /// ];
 ///
 /// let mut solver = solution::day16::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -70,8 +70,19 @@ impl aoc::Solver for Solution {
         "day16::Solution"
     }
 
-    fn read_lines(&mut self, lines: Vec<String>) {
-        lines.iter()
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "Proboscidea Volcanium",
+            url: "https://adventofcode.com/2022/day/16",
+            description: "Work out the steps to release the most pressure in 30 minutes. What is the most pressure you can release?",
+            tags: &["pathfinding", "graph", "dynamic-programming"],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, input: &aoc::input::Input) {
+        input.lines()
             .enumerate()
             .map(|(idx, line)| {
                 let mut valve = cave::Valve::from_str(line).unwrap();
@@ -88,7 +99,7 @@ impl aoc::Solver for Solution {
         let max = cave.walk(TIMEOUT).values()
             .copied()
             .max()
-            .unwrap();
+            .unwrap_or_default();
 
         format!("{}", max)
     }
@@ -101,7 +112,7 @@ impl aoc::Solver for Solution {
             .filter(|(myself, elephant)| myself.0 & elephant.0 == 0)
             .map(|(myself, elephant)| myself.1 + elephant.1)
             .max()
-            .unwrap();
+            .unwrap_or_default();
 
         format!("{}", max)
     }
@@ -128,7 +139,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day16").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day16").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 1651));
     }
@@ -137,8 +148,21 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day16").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day16").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 1707));
     }
+    #[test]
+    fn cancelled() {
+        let fs = LocalFilesystem{};
+        let mut solver = Solution::new();
+        solver.read_input(&fs.read_file("tests/fixtures/day16").unwrap());
+
+        let token = aoc::cancel::Token::new();
+        token.cancel();
+        aoc::cancel::install(token);
+
+        assert_eq!(solver.part1(), format!("{}", 0));
+        assert_eq!(solver.part2(), format!("{}", 0));
+    }
 }
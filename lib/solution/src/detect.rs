@@ -0,0 +1,189 @@
+//! Guess the day of an unlabelled puzzle input from its format.
+//!
+//! Each signature is cheap and only looks at the shape of the input, it
+//! doesn't solve anything. Inputs are trimmed at the end, copies from a
+//! clipboard often lose or gain a final newline.
+use aoc::args::day::Day;
+
+/// Format signature of the given day, if it has one.
+pub fn get_signature(day: &Day) -> Option<fn(&str) -> bool> {
+    match day {
+        Day::Day01 => Some(day01),
+        Day::Day02 => Some(day02),
+        Day::Day03 => Some(day03),
+        Day::Day04 => Some(day04),
+        Day::Day05 => Some(day05),
+        Day::Day06 => Some(day06),
+        Day::Day07 => Some(day07),
+        Day::Day08 => Some(day08),
+        Day::Day09 => Some(day09),
+        Day::Day10 => Some(day10),
+        Day::Day11 => Some(day11),
+        Day::Day12 => Some(day12),
+        Day::Day13 => Some(day13),
+        Day::Day14 => Some(day14),
+        Day::Day15 => Some(day15),
+        Day::Day16 => Some(day16),
+        _ => None,
+    }
+}
+
+/// Enabled days whose signature matches the input, in order.
+pub fn detect(input: &str) -> Vec<Day> {
+    let input = input.trim_end();
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    crate::days().into_iter()
+        .filter(|day| get_signature(day).is_some_and(|signature| signature(input)))
+        .collect()
+}
+
+fn all_lines(input: &str, check: impl Fn(&str) -> bool) -> bool {
+    input.lines().all(check)
+}
+
+fn is_number(s: &str) -> bool {
+    let s = s.strip_prefix('-').unwrap_or(s);
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Lines of the same length made of the given characters, no blank line.
+fn is_grid(input: &str, cell: impl Fn(u8) -> bool) -> bool {
+    let width = input.lines().next().map_or(0, str::len);
+    width > 1 && all_lines(input, |line| line.len() == width && line.bytes().all(&cell))
+}
+
+/// Calories, groups of numbers separated by blank lines.
+fn day01(input: &str) -> bool {
+    input.contains("\n\n") && all_lines(input, |line| line.is_empty() || is_number(line))
+}
+
+/// Strategy guide, `A Y`.
+fn day02(input: &str) -> bool {
+    all_lines(input, |line| matches!(line.as_bytes(), [b'A'..=b'C', b' ', b'X'..=b'Z']))
+}
+
+/// Rucksacks, letters with an even count by groups of three, not all of the
+/// same length as the heightmap.
+fn day03(input: &str) -> bool {
+    let lines = input.lines().count();
+    lines.is_multiple_of(3)
+        && all_lines(input, |line| !line.is_empty() && line.len().is_multiple_of(2) && line.bytes().all(|b| b.is_ascii_alphabetic()))
+        && !is_grid(input, |b| b.is_ascii_alphabetic())
+}
+
+/// Section assignments, `2-4,6-8`.
+fn day04(input: &str) -> bool {
+    all_lines(input, |line| {
+        line.split(',').map(|range| range.split('-').all(is_number) && range.contains('-')).collect::<Vec<bool>>() == [true, true]
+    })
+}
+
+/// Crate stacks with `[X]` and `move 1 from 2 to 1` lines.
+fn day05(input: &str) -> bool {
+    input.lines().next().is_some_and(|line| line.contains('['))
+        && input.lines().any(|line| line.starts_with("move "))
+}
+
+/// Datastream, a single line of letters.
+fn day06(input: &str) -> bool {
+    input.lines().count() == 1 && input.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+/// Terminal output, starts with `$ cd /`.
+fn day07(input: &str) -> bool {
+    input.starts_with("$ cd ")
+}
+
+/// Tree heights, a grid of digits.
+fn day08(input: &str) -> bool {
+    is_grid(input, |b| b.is_ascii_digit())
+}
+
+/// Rope motions, `R 4`.
+fn day09(input: &str) -> bool {
+    all_lines(input, |line| matches!(line.split_once(' '), Some(("R" | "L" | "U" | "D", steps)) if is_number(steps)))
+}
+
+/// CPU instructions, `noop` and `addx 3`.
+fn day10(input: &str) -> bool {
+    all_lines(input, |line| line == "noop" || line.strip_prefix("addx ").is_some_and(is_number))
+}
+
+/// Monkey notes.
+fn day11(input: &str) -> bool {
+    input.starts_with("Monkey 0:") && input.contains("Starting items:")
+}
+
+/// Heightmap, a grid of letters with a single `S` and `E`.
+fn day12(input: &str) -> bool {
+    is_grid(input, |b| b.is_ascii_lowercase() || b == b'S' || b == b'E')
+        && input.matches('S').count() == 1
+        && input.matches('E').count() == 1
+}
+
+/// Pairs of packets, nested lists of numbers.
+fn day13(input: &str) -> bool {
+    input.contains("\n\n")
+        && all_lines(input, |line| line.is_empty() || (line.starts_with('[') && line.ends_with(']')))
+        && input.bytes().all(|b| b.is_ascii_digit() || b"[],\n".contains(&b))
+}
+
+/// Rock paths, `498,4 -> 498,6`.
+fn day14(input: &str) -> bool {
+    all_lines(input, |line| {
+        line.split(" -> ").all(|point| matches!(point.split_once(','), Some((x, y)) if is_number(x) && is_number(y)))
+    })
+}
+
+/// Sensors and their closest beacon.
+fn day15(input: &str) -> bool {
+    all_lines(input, |line| line.starts_with("Sensor at x=") && line.contains("closest beacon is at x="))
+}
+
+/// Valves and their tunnels.
+fn day16(input: &str) -> bool {
+    all_lines(input, |line| line.starts_with("Valve ") && line.contains(" has flow rate="))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::day::Day;
+
+    use super::detect;
+
+    #[test]
+    fn inputs() {
+        for day in crate::days().into_iter().filter(|day| super::get_signature(day).is_some()) {
+            for path in [format!("tests/fixtures/day{:0>2}", day), format!("../../input/day{:0>2}", day)] {
+                if let Ok(input) = std::fs::read_to_string(&path) {
+                    assert_eq!(detect(&input), vec![day.clone()], "{}", path);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "all")]
+    #[test]
+    fn clipboard() {
+        assert_eq!(detect("noop\naddx 3\naddx -5"), vec![Day::Day10]);
+        assert_eq!(detect("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB\n\n"), vec![Day::Day16]);
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(detect(""), Vec::<Day>::new());
+        assert_eq!(detect("hello, world\n"), Vec::<Day>::new());
+    }
+
+    #[cfg(feature = "all")]
+    #[test]
+    fn borderline() {
+        assert_eq!(detect("abcd\n"), vec![Day::Day06]);
+        assert_eq!(detect("ab\ncdef\ngh\n"), vec![Day::Day03]);
+        assert_eq!(detect("abcdef\nghijkl\nmnopqr\n"), Vec::<Day>::new());
+        assert_eq!(detect("1\n\n2\n"), vec![Day::Day01]);
+    }
+}
@@ -0,0 +1,138 @@
+//! Synthetic inputs of growing size to see how the solutions scale.
+//!
+//! The generated inputs are valid puzzle inputs, but they are not meant to
+//! have interesting answers. They are deterministic, the same size always
+//! gives the same input.
+use aoc::args::{day::Day, part::Part};
+
+pub struct Generator {
+    /// Input sizes to time, in the unit of `generate`.
+    pub sizes: &'static [usize],
+    /// Parts that finish in reasonable time on generated inputs.
+    pub parts: &'static [Part],
+    /// Build an input with roughly `size` items (characters, trees, sensors).
+    pub generate: fn(usize) -> Vec<String>,
+}
+
+/// Input generator of the given day, if it has one and it's enabled.
+pub fn get_generator(day: &Day) -> Option<Generator> {
+    match day {
+        Day::Day06 if cfg!(feature = "day06") => Some(Generator {
+            sizes: &[1_000, 2_000, 4_000, 8_000, 16_000, 32_000],
+            parts: &[Part::Part1, Part::Part2],
+            generate: day06,
+        }),
+        Day::Day08 if cfg!(feature = "day08") => Some(Generator {
+            sizes: &[400, 900, 1_600, 3_600, 6_400],
+            parts: &[Part::Part1, Part::Part2],
+            generate: day08,
+        }),
+        Day::Day15 if cfg!(feature = "day15") => Some(Generator {
+            sizes: &[50, 100, 200, 400, 800],
+            parts: &[Part::Part1],
+            generate: day15,
+        }),
+        _ => None,
+    }
+}
+
+/// Xorshift, good enough for puzzle inputs and no dependency.
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Random(seed.max(1))
+    }
+
+    fn below(&mut self, max: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0 % max
+    }
+}
+
+/// Signal of `size` characters, both markers are only found at the end.
+fn day06(size: usize) -> Vec<String> {
+    let marker = "abcdefghijklmn";
+    let noise = "xyz".repeat(size.saturating_sub(marker.len()) / 3 + 1);
+
+    vec![format!("{}{}", &noise[..size.saturating_sub(marker.len())], marker)]
+}
+
+/// Square forest of about `size` trees.
+fn day08(size: usize) -> Vec<String> {
+    let side = ((size as f64).sqrt() as usize).max(1);
+    let mut random = Random::new(size as u64);
+
+    (0..side)
+        .map(|_| (0..side).map(|_| char::from(b'0' + random.below(10) as u8)).collect())
+        .collect()
+}
+
+/// `size` sensors along the row the first part checks.
+fn day15(size: usize) -> Vec<String> {
+    let mut random = Random::new(size as u64);
+
+    (0..size)
+        .map(|idx| {
+            let x = idx as i64 * 20;
+            let y = 2000000 + random.below(10) as i64 - 5;
+            let (dx, dy) = (random.below(10) as i64, random.below(10) as i64);
+
+            format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}", x, y, x + dx, y + dy)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::day::Day;
+    use aoc::input::Input;
+
+    use super::get_generator;
+
+    #[test]
+    fn generators() {
+        for day in Day::all() {
+            let generator = match get_generator(&day) {
+                Some(generator) => generator,
+                None => continue,
+            };
+
+            for size in generator.sizes.iter().take(2) {
+                let mut solver = crate::get_solver(&day);
+                solver.read_input(&Input::from((generator.generate)(*size)));
+
+                for part in generator.parts {
+                    assert!(solver.solve(part).is_ok(), "day {} part {} size {}", day, part, size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn day06() {
+        let input = super::day06(20);
+
+        assert_eq!(input, vec!["xyzxyzabcdefghijklmn".to_string()]);
+    }
+
+    #[test]
+    fn day08() {
+        let input = super::day08(16);
+
+        assert_eq!(input.len(), 4);
+        assert!(input.iter().all(|line| line.len() == 4 && line.chars().all(|c| c.is_ascii_digit())));
+        assert_eq!(input, super::day08(16));
+    }
+
+    #[test]
+    fn day15() {
+        let input = super::day15(3);
+
+        assert_eq!(input.len(), 3);
+        assert!(input[2].starts_with("Sensor at x=40, y="), "{}", input[2]);
+    }
+}
@@ -1,25 +1,137 @@
+#[cfg(feature = "day01")]
 pub mod day01;
+#[cfg(feature = "day02")]
 pub mod day02;
+#[cfg(feature = "day03")]
 pub mod day03;
+#[cfg(feature = "day04")]
 pub mod day04;
+#[cfg(feature = "day05")]
 pub mod day05;
+#[cfg(feature = "day06")]
 pub mod day06;
+#[cfg(feature = "day07")]
 pub mod day07;
+#[cfg(feature = "day08")]
 pub mod day08;
+#[cfg(feature = "day09")]
 pub mod day09;
+#[cfg(feature = "day10")]
 pub mod day10;
+#[cfg(feature = "day11")]
 pub mod day11;
+#[cfg(feature = "day12")]
 pub mod day12;
+#[cfg(feature = "day13")]
 pub mod day13;
+#[cfg(feature = "day14")]
 pub mod day14;
+#[cfg(feature = "day15")]
 pub mod day15;
+#[cfg(feature = "day16")]
 pub mod day16;
-//pub mod day17;
-//pub mod day18;
-//pub mod day19;
-//pub mod day20;
-//pub mod day21;
-//pub mod day22;
-//pub mod day23;
-//pub mod day24;
-//pub mod day25;
+//#[cfg(feature = "day17")] pub mod day17;
+//#[cfg(feature = "day18")] pub mod day18;
+//#[cfg(feature = "day19")] pub mod day19;
+//#[cfg(feature = "day20")] pub mod day20;
+//#[cfg(feature = "day21")] pub mod day21;
+//#[cfg(feature = "day22")] pub mod day22;
+//#[cfg(feature = "day23")] pub mod day23;
+//#[cfg(feature = "day24")] pub mod day24;
+//#[cfg(feature = "day25")] pub mod day25;
+
+pub mod detect;
+pub mod generate;
+
+use aoc::Solver;
+use aoc::args::day::Day;
+
+/// Days solved by the enabled features, in order.
+pub fn days() -> Vec<Day> {
+    Day::all().into_iter()
+        .filter(|day| get_solver(day).is_implemented())
+        .collect()
+}
+
+/// Solver of the given day, [`aoc::MissingSolution`] for days without one
+/// or with its feature disabled.
+pub fn get_solver(day: &Day) -> Box<dyn Solver> {
+    match day {
+        #[cfg(feature = "day01")]
+        Day::Day01 => Box::new(day01::Solution::new()),
+        #[cfg(feature = "day02")]
+        Day::Day02 => Box::new(day02::Solution::new()),
+        #[cfg(feature = "day03")]
+        Day::Day03 => Box::new(day03::Solution::new()),
+        #[cfg(feature = "day04")]
+        Day::Day04 => Box::new(day04::Solution::new()),
+        #[cfg(feature = "day05")]
+        Day::Day05 => Box::new(day05::Solution::new()),
+        #[cfg(feature = "day06")]
+        Day::Day06 => Box::new(day06::Solution::new()),
+        #[cfg(feature = "day07")]
+        Day::Day07 => Box::new(day07::Solution::new()),
+        #[cfg(feature = "day08")]
+        Day::Day08 => Box::new(day08::Solution::new()),
+        #[cfg(feature = "day09")]
+        Day::Day09 => Box::new(day09::Solution::new()),
+        #[cfg(feature = "day10")]
+        Day::Day10 => Box::new(day10::Solution::new()),
+        #[cfg(feature = "day11")]
+        Day::Day11 => Box::new(day11::Solution::new()),
+        #[cfg(feature = "day12")]
+        Day::Day12 => Box::new(day12::Solution::new()),
+        #[cfg(feature = "day13")]
+        Day::Day13 => Box::new(day13::Solution::new()),
+        #[cfg(feature = "day14")]
+        Day::Day14 => Box::new(day14::Solution::new()),
+        #[cfg(feature = "day15")]
+        Day::Day15 => Box::new(day15::Solution::new()),
+        #[cfg(feature = "day16")]
+        Day::Day16 => Box::new(day16::Solution::new()),
+        _ => Box::new(aoc::MissingSolution::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::args::day::Day;
+
+    // To make sure we load the right solver, and only for the enabled days.
+    #[test]
+    fn get_solver() {
+        let days = vec![
+            (Day::Day01, cfg!(feature = "day01")), (Day::Day02, cfg!(feature = "day02")),
+            (Day::Day03, cfg!(feature = "day03")), (Day::Day04, cfg!(feature = "day04")),
+            (Day::Day05, cfg!(feature = "day05")), (Day::Day06, cfg!(feature = "day06")),
+            (Day::Day07, cfg!(feature = "day07")), (Day::Day08, cfg!(feature = "day08")),
+            (Day::Day09, cfg!(feature = "day09")), (Day::Day10, cfg!(feature = "day10")),
+            (Day::Day11, cfg!(feature = "day11")), (Day::Day12, cfg!(feature = "day12")),
+            (Day::Day13, cfg!(feature = "day13")), (Day::Day14, cfg!(feature = "day14")),
+            (Day::Day15, cfg!(feature = "day15")), (Day::Day16, cfg!(feature = "day16")),
+            (Day::Day17, false), (Day::Day18, false), (Day::Day19, false), (Day::Day20, false),
+            (Day::Day21, false), (Day::Day22, false), (Day::Day23, false), (Day::Day24, false), (Day::Day25, false),
+        ];
+
+        for (day, implemented) in days {
+            if implemented {
+                assert_eq!(super::get_solver(&day).name(), &format!("day{}::Solution", day))
+            } else {
+                assert!(!super::get_solver(&day).is_implemented())
+            }
+        }
+    }
+
+    #[test]
+    fn metadata() {
+        for day in super::days() {
+            let metadata = super::get_solver(&day).metadata();
+
+            assert!(!metadata.title.is_empty(), "day {}", day);
+            assert_eq!(metadata.url, format!("https://adventofcode.com/2022/day/{}", day.to_string().trim_start_matches('0')));
+            assert!(metadata.examples > 0, "day {}", day);
+        }
+
+        assert_eq!(super::get_solver(&Day::Day17).metadata(), aoc::metadata::Metadata::default());
+    }
+}
@@ -0,0 +1,19 @@
+import os
+import tomllib
+from pathlib import Path
+
+
+def config_dir(key: str, default: str) -> Path:
+    """Same lookup as the runner: AOC_* variable, then aoc.toml from the
+    current directory up, relative to the file."""
+    value = os.environ.get(f'AOC_{key.upper()}')
+    if value:
+        return Path(value)
+
+    for directory in [Path.cwd(), *Path.cwd().parents]:
+        config = directory / 'aoc.toml'
+        if config.is_file():
+            with open(config, 'rb') as f:
+                return directory / tomllib.load(f).get(key, default)
+
+    return Path(default)
@@ -1,20 +1,30 @@
 #!/usr/bin/env python3
 import os
 import sys
+from pathlib import Path
 from string import Template
 
+from aoc_config import config_dir
+
+FIRST_YEAR = 2022
+
+
+def solution_dir(year: int) -> str:
+    """Solution crate of the year, the first year is in lib/solution."""
+    return 'lib/solution' if year == FIRST_YEAR else f'lib/solution{year}'
+
 
 def write_day_mod(day: int):
-    dir_path = f'lib/solution/src/day{day:02d}'
-    file_path =f'lib/solution/src/day{day:02d}/mod.rs'
+    dir_path = f'{solution_dir(target_year)}/src/day{day:02d}'
+    file_path = f'{dir_path}/mod.rs'
 
     if os.path.exists(file_path):
         return
 
-    with open("template/day_mod.rs") as f:
+    with open(config_dir('template_dir', 'template') / 'day_mod.rs') as f:
         template = Template(f.read())
 
-    output = template.substitute(day=target_day, padded_day=f'{target_day:02d}')
+    output = template.substitute(year=target_year, day=target_day, padded_day=f'{target_day:02d}')
 
     print(f"Write {file_path}")
 
@@ -25,12 +35,29 @@ def write_day_mod(day: int):
         f.write(output)
 
 
+def write_fixture(day: int):
+    if target_year == FIRST_YEAR:
+        file_path = config_dir('fixture_dir', 'lib/solution/tests/fixtures') / f'day{day:02d}'
+    else:
+        file_path = Path(solution_dir(target_year)) / 'tests' / 'fixtures' / f'day{day:02d}'
+        file_path.parent.mkdir(parents=True, exist_ok=True)
+
+    if file_path.exists():
+        return
+
+    print(f"Write {file_path}")
+    file_path.touch()
+
+
 def update_lib(day: int):
-    fpath = 'lib/solution/src/lib.rs'
+    fpath = f'{solution_dir(target_year)}/src/lib.rs'
     with open(fpath) as f:
         content = f.read()
 
-    new_content = content.replace(f'//pub mod day{day:02d};', f'pub mod day{day:02d};')
+    new_content = content.replace(
+        f'//#[cfg(feature = "day{day:02d}")] pub mod day{day:02d};',
+        f'#[cfg(feature = "day{day:02d}")]\npub mod day{day:02d};',
+    )
 
     if content == new_content:
         return
@@ -42,36 +69,36 @@ def update_lib(day: int):
 
 
 def update_runner(day: int):
-    fpath = 'bin/aoc2022/src/main.rs'
+    fpath = f'{solution_dir(target_year)}/src/lib.rs'
     with open(fpath) as f:
         content = f.read()
 
-    match_line = f'Day::Day{day:02d} => Box::new(solution::day{day:02d}::Solution::new()),'
-    marker = f'Day::Day{day:02d} => Box::new(aoc::MissingSolution::new()),'
+    match_line = f'Day::Day{day:02d} => Box::new(day{day:02d}::Solution::new()),'
+    marker = '        _ => Box::new(aoc::MissingSolution::new()),'
 
     if match_line in content:
         return
 
-    print(f'Add Day{day:02d} to aoc2022')
+    print(f'Add Day{day:02d} to get_solver')
 
-    new_content = content.replace(marker, match_line)
+    new_content = content.replace(marker, f'        #[cfg(feature = "day{day:02d}")]\n        {match_line}\n{marker}')
 
     with open(fpath, 'w', newline='\n', encoding="utf-8") as f:
         f.write(new_content)
 
 
 def update_runner_test(day: int):
-    fpath = 'bin/aoc2022/src/main.rs'
+    fpath = f'{solution_dir(target_year)}/src/lib.rs'
     with open(fpath) as f:
         content = f.read()
 
-    match_line = f'(Day::Day{day:02d}, true)'
+    match_line = f'(Day::Day{day:02d}, cfg!(feature = "day{day:02d}"))'
     marker = f'(Day::Day{day:02d}, false)'
 
     if match_line in content:
         return
 
-    print(f'Enable Day{day:02d} in aoc2022 tests')
+    print(f'Enable Day{day:02d} in get_solver tests')
 
     new_content = content.replace(marker, match_line)
 
@@ -80,12 +107,18 @@ def update_runner_test(day: int):
 
 
 if len(sys.argv) < 2:
-    print(f"{sys.argv[0]} <day>")
+    print(f"{sys.argv[0]} <day> [year]")
     sys.exit(1)
 
 target_day = int(sys.argv[1])
+target_year = int(sys.argv[2]) if len(sys.argv) > 2 else FIRST_YEAR
+
+if not os.path.exists(f'{solution_dir(target_year)}/src/lib.rs'):
+    print(f"{solution_dir(target_year)} doesn't exist, create the crate of {target_year} and register it in bin/aoc2022/src/main.rs")
+    sys.exit(1)
 
 write_day_mod(target_day)
+write_fixture(target_day)
 update_lib(target_day)
 update_runner(target_day)
 update_runner_test(target_day)
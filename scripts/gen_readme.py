@@ -4,6 +4,8 @@ import subprocess
 import sys
 from string import Template
 
+from aoc_config import config_dir
+
 
 def gen(day: int, part: int) -> str:
     # cmd = f"cargo run --quiet -- --day={day} --part={part} --time-it"
@@ -31,7 +33,7 @@ target_day = int(sys.argv[1])
 part1 = gen(target_day, 1)
 part2 = gen(target_day, 2)
 
-with open("template/day_readme.md") as f:
+with open(config_dir('template_dir', 'template') / 'day_readme.md') as f:
     template = Template(f.read())
     output = template.substitute(day=target_day, part1=part1, part2=part2)
 
@@ -2,7 +2,7 @@
 ///
 /// DESCRIPTION
 ///
-/// URL: <https://adventofcode.com/2022/day/${day}>
+/// URL: <https://adventofcode.com/${year}/day/${day}>
 ///
 /// # Part 1
 ///
@@ -13,7 +13,7 @@
 /// ];
 ///
 /// let mut solver = solution::day${padded_day}::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part1_solution = solver.part1();
 /// ```
@@ -27,7 +27,7 @@
 /// ];
 ///
 /// let mut solver = solution::day${padded_day}::Solution::new();
-/// solver.read_lines(lines.iter().map(|s| s.to_string()).collect());
+/// solver.read_input(&lines.into_iter().collect());
 ///
 /// let part2_solution = solver.part2();
 /// ```
@@ -39,7 +39,18 @@ impl aoc::Solver for Solution {
         "day${padded_day}::Solution"
     }
 
-    fn read_lines(&mut self, _: Vec<String>) {
+    fn metadata(&self) -> aoc::metadata::Metadata {
+        aoc::metadata::Metadata {
+            title: "NAME",
+            url: "https://adventofcode.com/${year}/day/${day}",
+            description: "DESCRIPTION",
+            tags: &[],
+            examples: 1,
+            ..Default::default()
+        }
+    }
+
+    fn read_input(&mut self, _: &aoc::input::Input) {
         todo!()
     }
 
@@ -73,7 +84,7 @@ mod tests {
     fn example1_part1() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day${padded_day}").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day${padded_day}").unwrap());
 
         assert_eq!(solver.part1(), format!("{}", 0));
     }
@@ -82,7 +93,7 @@ mod tests {
     fn example1_part2() {
         let fs = LocalFilesystem{};
         let mut solver = Solution::new();
-        solver.read_lines(fs.read_file("tests/fixtures/day${padded_day}").unwrap());
+        solver.read_input(&fs.read_file("tests/fixtures/day${padded_day}").unwrap());
 
         assert_eq!(solver.part2(), format!("{}", 0));
     }